@@ -140,7 +140,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sportsbook_odds = Odds::new_american(-110);
     let decimal_equiv = sportsbook_odds.to_decimal()?;
     let implied_prob = sportsbook_odds.implied_probability()?;
-    let breakeven_rate = implied_prob * 100.0;
+    let breakeven_rate = sportsbook_odds.break_even_percentage()?;
 
     println!("   American: {}", sportsbook_odds);
     println!("   Decimal equivalent: {:.3}", decimal_equiv);
@@ -4,7 +4,7 @@
 //! larger applications, including JSON serialization, database storage,
 //! and API integration patterns.
 
-use odds_converter::{Odds, OddsError};
+use odds_converter::{analysis, batch, Market, Odds};
 use std::collections::HashMap;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -109,12 +109,8 @@ fn batch_processing_example() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // Parse all odds, collecting successes and failures
-    let results: Vec<Result<Odds, OddsError>> =
-        odds_strings.iter().map(|s| s.parse::<Odds>()).collect();
-
-    let successful_odds: Vec<&Odds> = results.iter().filter_map(|r| r.as_ref().ok()).collect();
-
-    let failed_parses: Vec<&OddsError> = results.iter().filter_map(|r| r.as_ref().err()).collect();
+    let (parsed_odds, failed_parses) = batch::parse_all(odds_strings);
+    let successful_odds: Vec<&Odds> = parsed_odds.iter().collect();
 
     println!(
         "   Successfully parsed: {}/{}",
@@ -122,6 +118,9 @@ fn batch_processing_example() -> Result<(), Box<dyn std::error::Error>> {
         odds_strings.len()
     );
     println!("   Failed to parse: {}", failed_parses.len());
+    for (input, error) in &failed_parses {
+        println!("     '{}': {}", input, error);
+    }
 
     // Convert all successful odds to decimal for comparison
     println!("\n   Converted to decimal format:");
@@ -132,31 +131,20 @@ fn batch_processing_example() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Find the best and worst odds
-    if let (Some(best), Some(worst)) = find_best_worst_odds(&successful_odds)? {
-        println!("\n   Best odds (highest return): {:.3}", best);
-        println!("   Worst odds (lowest return): {:.3}", worst);
+    if let Some((best, worst)) = analysis::best_worst(&parsed_odds)? {
+        println!(
+            "\n   Best odds (highest return): {:.3}",
+            parsed_odds[best].to_decimal()?
+        );
+        println!(
+            "   Worst odds (lowest return): {:.3}",
+            parsed_odds[worst].to_decimal()?
+        );
     }
 
     Ok(())
 }
 
-fn find_best_worst_odds(
-    odds: &[&Odds],
-) -> Result<(Option<f64>, Option<f64>), Box<dyn std::error::Error>> {
-    if odds.is_empty() {
-        return Ok((None, None));
-    }
-
-    let decimals: Result<Vec<f64>, _> = odds.iter().map(|o| o.to_decimal()).collect();
-
-    let decimals = decimals?;
-
-    let best = decimals.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
-    let worst = decimals.iter().fold(f64::INFINITY, |a, &b| a.min(b));
-
-    Ok((Some(best), Some(worst)))
-}
-
 fn error_handling_patterns() -> Result<(), Box<dyn std::error::Error>> {
     let test_inputs = vec![
         "150",     // Valid American (missing +)
@@ -194,90 +182,34 @@ fn error_handling_patterns() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-// Custom data structure for a betting market
-#[derive(Debug)]
-struct BettingMarket {
-    description: String,
-    outcomes: Vec<MarketOutcome>,
-}
-
-#[derive(Debug)]
-struct MarketOutcome {
-    name: String,
-    odds: Odds,
-    is_favorite: bool,
-}
-
-impl BettingMarket {
-    fn new(description: String) -> Self {
-        Self {
-            description,
-            outcomes: Vec::new(),
-        }
-    }
-
-    fn add_outcome(&mut self, name: String, odds: Odds) -> Result<(), Box<dyn std::error::Error>> {
-        odds.validate()?;
-
-        let is_favorite = odds.implied_probability()? > 0.5;
-
-        self.outcomes.push(MarketOutcome {
-            name,
-            odds,
-            is_favorite,
-        });
-
-        Ok(())
-    }
-
-    fn total_probability(&self) -> Result<f64, Box<dyn std::error::Error>> {
-        let mut total = 0.0;
-        for outcome in &self.outcomes {
-            total += outcome.odds.implied_probability()?;
-        }
-        Ok(total)
-    }
-
-    fn get_favorite(&self) -> Option<&MarketOutcome> {
-        self.outcomes.iter().min_by(|a, b| {
-            a.odds
-                .to_decimal()
-                .unwrap_or(f64::INFINITY)
-                .partial_cmp(&b.odds.to_decimal().unwrap_or(f64::INFINITY))
-                .unwrap_or(std::cmp::Ordering::Equal)
-        })
-    }
-}
-
 fn custom_structures_example() -> Result<(), Box<dyn std::error::Error>> {
-    let mut market = BettingMarket::new("Manchester City vs Liverpool".to_string());
-
-    market.add_outcome("Manchester City Win".to_string(), Odds::new_decimal(2.10))?;
-    market.add_outcome("Draw".to_string(), Odds::new_decimal(3.40))?;
-    market.add_outcome("Liverpool Win".to_string(), Odds::new_decimal(3.75))?;
+    let market = Market::new("Manchester City vs Liverpool")
+        .add("Manchester City Win", Odds::new_decimal(2.10))?
+        .add("Draw", Odds::new_decimal(3.40))?
+        .add("Liverpool Win", Odds::new_decimal(3.75))?;
 
-    println!("   Market: {}", market.description);
+    println!("   Market: {}", market.description());
     println!(
         "   Total probability: {:.1}%",
         market.total_probability()? * 100.0
     );
 
-    if let Some(favorite) = market.get_favorite() {
-        println!("   Favorite: {} at {}", favorite.name, favorite.odds);
+    if let Some(favorite) = market.favorite() {
+        println!("   Favorite: {} at {}", favorite.name(), favorite.odds());
     }
 
     println!("   All outcomes:");
-    for outcome in &market.outcomes {
-        let prob = outcome.odds.implied_probability()?;
-        let status = if outcome.is_favorite {
+    for outcome in market.outcomes() {
+        let prob = outcome.odds().implied_probability()?;
+        let status = if outcome.is_favorite() {
             " (favorite)"
         } else {
             ""
         };
         println!(
             "     {} - {} ({:.1}%){}",
-            outcome.name,
-            outcome.odds,
+            outcome.name(),
+            outcome.odds(),
             prob * 100.0,
             status
         );
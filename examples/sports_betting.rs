@@ -6,7 +6,7 @@
 //! - Expected value calculations
 //! - Line shopping comparisons
 
-use odds_converter::Odds;
+use odds_converter::{arbitrage, probability, Market, Odds};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("=== Sports Betting Applications ===\n");
@@ -103,23 +103,22 @@ fn detect_arbitrage() -> Result<(), Box<dyn std::error::Error>> {
     );
     println!("   Total implied probability: {:.1}%", total_prob * 100.0);
 
-    if total_prob < 1.0 {
-        let profit_margin = (1.0 - total_prob) * 100.0;
+    if let Some(opportunity) = arbitrage::detect(&[book_a_lakers, book_b_warriors])? {
         println!(
             "   🎯 ARBITRAGE OPPORTUNITY! Profit margin: {:.2}%",
-            profit_margin
+            opportunity.profit_margin * 100.0
         );
 
         // Calculate optimal bet sizes for $1000 total
         let total_stake = 1000.0;
-        let lakers_stake = total_stake * lakers_prob / total_prob;
-        let warriors_stake = total_stake * warriors_prob / total_prob;
+        let lakers_stake = total_stake * opportunity.stake_fractions[0];
+        let warriors_stake = total_stake * opportunity.stake_fractions[1];
 
         println!("   Optimal bet allocation for $1000:");
         println!("     Lakers: ${:.2}", lakers_stake);
         println!("     Warriors: ${:.2}", warriors_stake);
 
-        let guaranteed_profit = total_stake * (1.0 - total_prob);
+        let guaranteed_profit = total_stake * opportunity.profit_margin;
         println!("   Guaranteed profit: ${:.2}", guaranteed_profit);
     } else {
         println!(
@@ -154,7 +153,7 @@ fn calculate_expected_value() -> Result<(), Box<dyn std::error::Error>> {
     let payout = market_odds.to_decimal()? * stake;
     let profit = payout - stake;
 
-    let ev = (your_estimate * profit) + ((1.0 - your_estimate) * (-stake));
+    let ev = market_odds.expected_value(your_estimate, stake)?;
 
     println!("   Expected Value calculation for $100 bet:");
     println!(
@@ -183,7 +182,7 @@ fn calculate_expected_value() -> Result<(), Box<dyn std::error::Error>> {
 fn analyze_market_probabilities() -> Result<(), Box<dyn std::error::Error>> {
     println!("   Horse Racing: 5-Horse Field");
 
-    let horses = vec![
+    let horses = [
         ("Thunder Bolt", Odds::new_fractional(2, 1)), // 2/1
         ("Speed Demon", Odds::new_fractional(5, 2)),  // 5/2
         ("Lucky Star", Odds::new_american(400)),      // +400
@@ -191,15 +190,14 @@ fn analyze_market_probabilities() -> Result<(), Box<dyn std::error::Error>> {
         ("Wind Runner", Odds::new_american(-150)),    // -150 (favorite)
     ];
 
-    let mut total_prob = 0.0;
+    let prices: Vec<Odds> = horses.iter().map(|(_, odds)| *odds).collect();
+    let summary = probability::market_summary(&prices)?;
 
     println!("   Horse            Odds        Decimal    Probability");
     println!("   ─────────────────────────────────────────────────────");
 
-    for (horse, odds) in &horses {
+    for ((horse, odds), prob) in horses.iter().zip(&summary.per_outcome) {
         let decimal = odds.to_decimal()?;
-        let prob = odds.implied_probability()?;
-        total_prob += prob;
 
         println!(
             "   {:12} {:10} {:7.2}    {:5.1}%",
@@ -211,16 +209,18 @@ fn analyze_market_probabilities() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("   ─────────────────────────────────────────────────────");
-    println!("   Total market probability: {:.1}%", total_prob * 100.0);
-
-    let overround = (total_prob - 1.0) * 100.0;
-    println!("   Bookmaker margin (overround): {:.1}%", overround);
+    println!("   Total market probability: {:.1}%", summary.total * 100.0);
+    println!(
+        "   Bookmaker margin (overround): {:.1}%",
+        summary.overround * 100.0
+    );
 
     // True probabilities (removing overround)
     println!("\n   True probabilities (removing bookmaker margin):");
-    for (horse, odds) in &horses {
-        let market_prob = odds.implied_probability()?;
-        let true_prob = market_prob / total_prob;
+    let true_probs = Market::from_odds(prices).fair_probabilities()?;
+    for (((horse, _), market_prob), true_prob) in
+        horses.iter().zip(&summary.per_outcome).zip(true_probs)
+    {
         println!(
             "   {:12} {:.1}% (was {:.1}%)",
             horse,
@@ -0,0 +1,627 @@
+//! Evaluation of odds against realized results.
+//!
+//! This module contains scoring rules and summary statistics for judging how well
+//! a set of prices predicted the outcomes that actually happened, along with
+//! measures of how a bookmaker has shaded the prices within a market.
+
+use crate::conversions::normalized_probabilities;
+use crate::{math, Odds, OddsError};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Calculates the Brier score of a set of odds against realized outcomes.
+///
+/// The Brier score is the mean squared difference between each price's implied
+/// probability and the realized result (1.0 for a win, 0.0 for a loss). Lower is
+/// better: 0.0 is a perfect forecast and always predicting 50% scores 0.25.
+///
+/// # Arguments
+///
+/// * `odds` - The price of each event
+/// * `outcomes` - Whether each event happened, in the same order as `odds`
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the Brier score, or an `Err(OddsError)` if the
+/// slices are empty, differ in length, or contain invalid odds.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::brier_score;
+/// use odds_converter::Odds;
+///
+/// let odds = [Odds::new_decimal(2.0), Odds::new_decimal(2.0)];
+/// assert_eq!(brier_score(&odds, &[true, false]).unwrap(), 0.25);
+/// ```
+pub fn brier_score(odds: &[Odds], outcomes: &[bool]) -> Result<f64, OddsError> {
+    let probabilities = paired_probabilities(odds, outcomes)?;
+
+    let total: f64 = probabilities
+        .iter()
+        .zip(outcomes)
+        .map(|(probability, &won)| {
+            let error = probability - if won { 1.0 } else { 0.0 };
+            error * error
+        })
+        .sum();
+
+    Ok(total / probabilities.len() as f64)
+}
+
+/// Smallest probability used by [`log_loss`], so certain-looking prices stay finite.
+const LOG_LOSS_EPSILON: f64 = 1e-15;
+
+/// Calculates the log-loss of a set of odds against realized outcomes.
+///
+/// Log-loss is the mean negative log-likelihood of the realized results under the
+/// implied probabilities: `-ln(p)` for a winner and `-ln(1 - p)` for a loser.
+/// Lower is better. Probabilities are clamped to `[1e-15, 1 - 1e-15]` so that a
+/// price implying certainty (decimal 1.0) yields a large but finite penalty.
+///
+/// # Arguments
+///
+/// * `odds` - The price of each event
+/// * `outcomes` - Whether each event happened, in the same order as `odds`
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the log-loss, or an `Err(OddsError)` if the
+/// slices are empty, differ in length, or contain invalid odds.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::log_loss;
+/// use odds_converter::Odds;
+///
+/// let odds = [Odds::new_decimal(2.0), Odds::new_decimal(2.0)];
+/// let loss = log_loss(&odds, &[true, false]).unwrap();
+/// assert!((loss - std::f64::consts::LN_2).abs() < 1e-12);
+/// ```
+pub fn log_loss(odds: &[Odds], outcomes: &[bool]) -> Result<f64, OddsError> {
+    let probabilities = paired_probabilities(odds, outcomes)?;
+
+    let total: f64 = probabilities
+        .iter()
+        .zip(outcomes)
+        .map(|(probability, &won)| {
+            let probability = probability.clamp(LOG_LOSS_EPSILON, 1.0 - LOG_LOSS_EPSILON);
+            if won {
+                -math::ln(probability)
+            } else {
+                -math::ln(1.0 - probability)
+            }
+        })
+        .sum();
+
+    Ok(total / probabilities.len() as f64)
+}
+
+/// Groups odds into equal-width probability bins for calibration plots.
+///
+/// The range `[0, 1]` is split into `num_bins` equal bins, and each price is placed
+/// in the bin containing its implied probability (a probability of exactly 1.0
+/// falls in the last bin). For well-calibrated prices, each bin's observed hit
+/// rate is close to its mean predicted probability.
+///
+/// # Arguments
+///
+/// * `odds` - The price of each event
+/// * `outcomes` - Whether each event happened, in the same order as `odds`
+/// * `num_bins` - Number of bins to split `[0, 1]` into (must be positive)
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<(f64, f64, usize)>)` with one `(mean predicted probability,
+/// observed hit rate, count)` entry per bin, ordered from the lowest probabilities
+/// to the highest. Empty bins are reported as `(0.0, 0.0, 0)`. Returns an
+/// `Err(OddsError)` if the inputs are empty, differ in length, contain invalid
+/// odds, or `num_bins` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::calibration_bins;
+/// use odds_converter::Odds;
+///
+/// let odds = [Odds::new_decimal(4.0), Odds::new_decimal(1.25)];
+/// let bins = calibration_bins(&odds, &[false, true], 2).unwrap();
+/// assert_eq!(bins, vec![(0.25, 0.0, 1), (0.8, 1.0, 1)]);
+/// ```
+pub fn calibration_bins(
+    odds: &[Odds],
+    outcomes: &[bool],
+    num_bins: usize,
+) -> Result<Vec<(f64, f64, usize)>, OddsError> {
+    if num_bins == 0 {
+        return Err(OddsError::ValueOutOfRange(
+            "Number of calibration bins must be greater than 0".to_string(),
+        ));
+    }
+    let probabilities = paired_probabilities(odds, outcomes)?;
+
+    let mut predicted = vec![0.0; num_bins];
+    let mut hits = vec![0usize; num_bins];
+    let mut counts = vec![0usize; num_bins];
+    for (probability, &won) in probabilities.iter().zip(outcomes) {
+        let bin = ((probability * num_bins as f64) as usize).min(num_bins - 1);
+        predicted[bin] += probability;
+        counts[bin] += 1;
+        if won {
+            hits[bin] += 1;
+        }
+    }
+
+    Ok((0..num_bins)
+        .map(|bin| match counts[bin] {
+            0 => (0.0, 0.0, 0),
+            count => (
+                predicted[bin] / count as f64,
+                hits[bin] as f64 / count as f64,
+                count,
+            ),
+        })
+        .collect())
+}
+
+/// Summarizes closing-line value (CLV) over a history of bets.
+///
+/// The CLV of a single bet is `my_decimal / closing_decimal - 1`: the percentage by
+/// which the price taken exceeded the final price before the event started. A bet
+/// beats the close when its CLV is strictly positive; a bet taken at exactly the
+/// closing price counts as a miss.
+///
+/// # Arguments
+///
+/// * `bets` - `(my_odds, closing_odds)` pairs for each bet placed
+///
+/// # Returns
+///
+/// Returns `Ok((average_clv, beat_count, miss_count))`, or an `Err(OddsError)` if
+/// the history is empty or any price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::clv_record;
+/// use odds_converter::Odds;
+///
+/// let bets = [(Odds::new_decimal(2.2), Odds::new_decimal(2.0))];
+/// let (average, beat, missed) = clv_record(&bets).unwrap();
+/// assert!((average - 0.1).abs() < 1e-12);
+/// assert_eq!((beat, missed), (1, 0));
+/// ```
+pub fn clv_record(bets: &[(Odds, Odds)]) -> Result<(f64, u32, u32), OddsError> {
+    if bets.is_empty() {
+        return Err(OddsError::ValueOutOfRange(
+            "At least one bet is required".to_string(),
+        ));
+    }
+
+    let mut total = 0.0;
+    let mut beat = 0;
+    let mut missed = 0;
+    for (mine, closing) in bets {
+        mine.validate()?;
+        closing.validate()?;
+
+        let clv = mine.to_decimal()? / closing.to_decimal()? - 1.0;
+        total += clv;
+        if clv > 0.0 {
+            beat += 1;
+        } else {
+            missed += 1;
+        }
+    }
+
+    Ok((total / bets.len() as f64, beat, missed))
+}
+
+/// Finds the best available price for one side of a market.
+///
+/// This is the core line-shopping primitive: given the price offered by each book
+/// for the same side, it returns the one paying the most (the highest decimal
+/// odds). Ties are resolved in favour of the earliest price.
+///
+/// # Arguments
+///
+/// * `prices` - The price offered for the same side by each book
+///
+/// # Returns
+///
+/// Returns `Ok((index, odds))` for the longest price, or an `Err(OddsError)` if
+/// the slice is empty or any price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::best_price_for_side;
+/// use odds_converter::Odds;
+///
+/// let prices = [Odds::new_american(-110), Odds::new_american(-105)];
+/// let (index, best) = best_price_for_side(&prices).unwrap();
+/// assert_eq!(index, 1);
+/// assert_eq!(best.to_american().unwrap(), -105);
+/// ```
+pub fn best_price_for_side(prices: &[Odds]) -> Result<(usize, Odds), OddsError> {
+    let mut best: Option<(usize, f64)> = None;
+    for (index, price) in prices.iter().enumerate() {
+        price.validate()?;
+        let decimal = price.to_decimal()?;
+        if best.map_or(true, |(_, best_decimal)| decimal > best_decimal) {
+            best = Some((index, decimal));
+        }
+    }
+
+    match best {
+        Some((index, _)) => Ok((index, prices[index])),
+        None => Err(OddsError::ValueOutOfRange(
+            "At least one price is required".to_string(),
+        )),
+    }
+}
+
+/// Finds the indices of the best and worst prices in a slice.
+///
+/// The best price pays the most (highest decimal odds) and the worst pays the
+/// least. Prices may be in any mix of formats. Ties are resolved in favour of the
+/// earliest price.
+///
+/// # Arguments
+///
+/// * `odds` - The prices to compare
+///
+/// # Returns
+///
+/// Returns `Ok(Some((best, worst)))` with the indices of the highest and lowest
+/// decimal odds, `Ok(None)` for an empty slice, or an `Err(OddsError)` if any
+/// price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::best_worst;
+/// use odds_converter::Odds;
+///
+/// let prices = [Odds::new_american(-110), Odds::new_decimal(2.2), Odds::new_fractional(1, 2)];
+/// assert_eq!(best_worst(&prices).unwrap(), Some((1, 2)));
+/// assert_eq!(best_worst(&[]).unwrap(), None);
+/// ```
+pub fn best_worst(odds: &[Odds]) -> Result<Option<(usize, usize)>, OddsError> {
+    let mut extremes: Option<((usize, f64), (usize, f64))> = None;
+    for (index, price) in odds.iter().enumerate() {
+        price.validate()?;
+        let decimal = price.to_decimal()?;
+        extremes = Some(match extremes {
+            None => ((index, decimal), (index, decimal)),
+            Some((best, worst)) => (
+                if decimal > best.1 {
+                    (index, decimal)
+                } else {
+                    best
+                },
+                if decimal < worst.1 {
+                    (index, decimal)
+                } else {
+                    worst
+                },
+            ),
+        });
+    }
+
+    Ok(extremes.map(|((best, _), (worst, _))| (best, worst)))
+}
+
+/// Scores how closely a soft book's market matches a sharp reference market.
+///
+/// Both markets are devigged by normalizing their implied probabilities to sum to
+/// 1, and the score is the mean absolute difference between the two sets of fair
+/// probabilities. A score of 0.0 means the soft book agrees exactly with the sharp
+/// reference; larger scores mean the soft book is further from efficient.
+///
+/// # Arguments
+///
+/// * `soft` - The soft book's price for each outcome
+/// * `sharp` - The sharp book's price for each outcome, in the same order as `soft`
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the mean absolute difference, or an
+/// `Err(OddsError)` if the markets are empty, differ in length, or contain invalid
+/// odds.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::efficiency_vs_reference;
+/// use odds_converter::Odds;
+///
+/// let soft = [Odds::new_decimal(1.8), Odds::new_decimal(2.0)];
+/// let sharp = [Odds::new_decimal(1.9), Odds::new_decimal(1.9)];
+/// assert!(efficiency_vs_reference(&soft, &sharp).unwrap() > 0.0);
+/// ```
+pub fn efficiency_vs_reference(soft: &[Odds], sharp: &[Odds]) -> Result<f64, OddsError> {
+    if soft.len() != sharp.len() {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Markets must have the same outcomes, got {} soft and {} sharp prices",
+            soft.len(),
+            sharp.len()
+        )));
+    }
+
+    let soft_fair = normalized_probabilities(soft)?;
+    let sharp_fair = normalized_probabilities(sharp)?;
+    let total: f64 = soft_fair
+        .iter()
+        .zip(&sharp_fair)
+        .map(|(soft, sharp)| math::abs(soft - sharp))
+        .sum();
+
+    Ok(total / soft_fair.len() as f64)
+}
+
+/// Measures how surprising a single result was given its price.
+///
+/// The outcome is treated as a Bernoulli trial with success probability `p`, the
+/// implied probability of the odds, so its standard deviation is
+/// `sqrt(p * (1 - p))`. The result is the distance between the realized value
+/// (1.0 for a win, 0.0 for a loss) and `p`, in standard deviations. It is always
+/// non-negative: an even-money bet is 1 sigma away whether it wins or loses, while
+/// a heavy favorite losing is many sigma away.
+///
+/// # Arguments
+///
+/// * `odds` - The price of the event
+/// * `realized` - Whether the event happened
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the number of standard deviations, or an
+/// `Err(OddsError)` if the odds are invalid or priced the event as certain (decimal
+/// 1.0) and it did not happen.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::result_sigma;
+/// use odds_converter::Odds;
+///
+/// let sigma = result_sigma(&Odds::new_american(-400), false).unwrap();
+/// assert!((sigma - 2.0).abs() < 1e-12);
+/// ```
+pub fn result_sigma(odds: &Odds, realized: bool) -> Result<f64, OddsError> {
+    odds.validate()?;
+    let probability = odds.implied_probability()?;
+    let difference = math::abs(if realized { 1.0 } else { 0.0 } - probability);
+    if difference == 0.0 {
+        return Ok(0.0);
+    }
+
+    let standard_deviation = math::sqrt(probability * (1.0 - probability));
+    if standard_deviation == 0.0 {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Odds of {} price the event as certain, so it cannot lose",
+            odds
+        )));
+    }
+
+    Ok(difference / standard_deviation)
+}
+
+/// Bisection steps used by the root finders in this module.
+const BISECTION_ITERATIONS: usize = 100;
+
+/// Measures favorite-longshot bias within a single market.
+///
+/// The statistic is the Pearson correlation between each outcome's log decimal odds
+/// and its share of the market overround. The prices do not say how the bookmaker
+/// split the overround, so the shares come from a model: fair probabilities are
+/// estimated with the power method, `p_i = q_i^k` where `q_i` is the implied
+/// probability and `k` is chosen so the `p_i` sum to 1, and an outcome's share of
+/// the overround is then `(q_i - p_i) / (sum(q) - 1)`. The result describes how the
+/// power method attributes the margin, not a split observed in the market.
+///
+/// At least three outcomes are required, since a correlation over two points is
+/// always ±1 and carries no signal. The result is clamped to `[-1, 1]`. Positive
+/// values mean the longer-priced outcomes absorb more of the margin, which is the
+/// signature of favorite-longshot bias. Returns 0.0 when either variable is
+/// constant, for example in a market without overround or where every outcome has
+/// the same price.
+///
+/// # Arguments
+///
+/// * `odds` - The price of every outcome in the market
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the correlation, or an `Err(OddsError)` if there are
+/// fewer than three outcomes or any price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::longshot_bias;
+/// use odds_converter::Odds;
+///
+/// let market = [Odds::new_decimal(1.2), Odds::new_decimal(6.0), Odds::new_decimal(9.0)];
+/// assert!(longshot_bias(&market).unwrap() > 0.0);
+/// ```
+pub fn longshot_bias(odds: &[Odds]) -> Result<f64, OddsError> {
+    if odds.len() < 3 {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "At least three outcomes are required, got {}",
+            odds.len()
+        )));
+    }
+
+    let mut log_odds = Vec::with_capacity(odds.len());
+    let mut implied = Vec::with_capacity(odds.len());
+    for price in odds {
+        price.validate()?;
+        let decimal = price.to_decimal()?;
+        log_odds.push(math::ln(decimal));
+        implied.push(1.0 / decimal);
+    }
+
+    let overround = implied.iter().sum::<f64>() - 1.0;
+    if overround == 0.0 {
+        return Ok(0.0);
+    }
+
+    let exponent = power_exponent(&implied);
+    let shares: Vec<f64> = implied
+        .iter()
+        .map(|&q| (q - math::powf(q, exponent)) / overround)
+        .collect();
+
+    // Rounding can push the correlation just outside [-1, 1]
+    Ok(pearson_correlation(&log_odds, &shares).clamp(-1.0, 1.0))
+}
+
+/// Fits the insider-trading proportion `z` of Shin's model to a market.
+///
+/// Shin (1993) explains the overround as the bookmaker's protection against
+/// bettors with inside information, who make up a proportion `z` of the money.
+/// Given implied probabilities `q_i` summing to `B`, the fair probabilities are
+///
+/// `p_i(z) = (sqrt(z^2 + 4 (1 - z) q_i^2 / B) - z) / (2 (1 - z))`
+///
+/// and `z` is the value in `[0, 1)` for which they sum to 1, found by bisection.
+/// A market without overround has `z = 0`.
+///
+/// # Arguments
+///
+/// * `odds` - The price of every outcome in the market
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing `z`, or an `Err(OddsError)` if there are fewer than
+/// two outcomes, the implied probabilities sum to less than 1, or any price is
+/// invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::analysis::shin_z;
+/// use odds_converter::Odds;
+///
+/// let fair = [Odds::new_decimal(2.0), Odds::new_decimal(2.0)];
+/// assert!(shin_z(&fair).unwrap() < 1e-12);
+///
+/// let vigged = [Odds::new_american(-110), Odds::new_american(-110)];
+/// assert!(shin_z(&vigged).unwrap() > 0.0);
+/// ```
+pub fn shin_z(odds: &[Odds]) -> Result<f64, OddsError> {
+    if odds.len() < 2 {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "At least two outcomes are required, got {}",
+            odds.len()
+        )));
+    }
+
+    let mut implied = Vec::with_capacity(odds.len());
+    for price in odds {
+        price.validate()?;
+        implied.push(price.implied_probability()?);
+    }
+
+    let booksum: f64 = implied.iter().sum();
+    if booksum < 1.0 {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Shin's model requires implied probabilities summing to at least 1, got: {}",
+            booksum
+        )));
+    }
+
+    // The fair probabilities sum to sqrt(B) >= 1 at z = 0 and fall below 1 as z
+    // approaches 1, so the root is bracketed by [0, 1)
+    let excess = |z: f64| {
+        implied
+            .iter()
+            .map(|q| {
+                (math::sqrt(z * z + 4.0 * (1.0 - z) * q * q / booksum) - z) / (2.0 * (1.0 - z))
+            })
+            .sum::<f64>()
+            - 1.0
+    };
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if excess(mid) > 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok((low + high) / 2.0)
+}
+
+/// Finds `k` such that the implied probabilities raised to `k` sum to 1.
+fn power_exponent(implied: &[f64]) -> f64 {
+    let excess = |k: f64| implied.iter().map(|&q| math::powf(q, k)).sum::<f64>() - 1.0;
+
+    // The sum falls as k grows, so bracket the root and bisect
+    let (mut low, mut high) = (0.0, 1.0);
+    while excess(high) > 0.0 && high < f64::MAX / 2.0 {
+        low = high;
+        high *= 2.0;
+    }
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if excess(mid) > 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// Pearson correlation of two equal-length samples, or 0.0 if either is constant.
+fn pearson_correlation(xs: &[f64], ys: &[f64]) -> f64 {
+    let n = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = ys.iter().sum::<f64>() / n;
+
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    let mut variance_y = 0.0;
+    for (x, y) in xs.iter().zip(ys) {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x) * (x - mean_x);
+        variance_y += (y - mean_y) * (y - mean_y);
+    }
+
+    let denominator = math::sqrt(variance_x * variance_y);
+    if denominator == 0.0 {
+        0.0
+    } else {
+        covariance / denominator
+    }
+}
+
+/// Validates a set of odds against its outcomes and returns the implied probabilities.
+fn paired_probabilities(odds: &[Odds], outcomes: &[bool]) -> Result<Vec<f64>, OddsError> {
+    if odds.len() != outcomes.len() {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Expected one outcome per price, got {} prices and {} outcomes",
+            odds.len(),
+            outcomes.len()
+        )));
+    }
+    if odds.is_empty() {
+        return Err(OddsError::ValueOutOfRange(
+            "At least one price is required".to_string(),
+        ));
+    }
+
+    odds.iter()
+        .map(|price| {
+            price.validate()?;
+            price.implied_probability()
+        })
+        .collect()
+}
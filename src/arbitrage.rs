@@ -0,0 +1,78 @@
+//! Arbitrage detection across bookmakers.
+//!
+//! When the best available prices for every outcome of an event come from
+//! different books, their implied probabilities can sum to less than 1. Staking on
+//! every outcome in the right proportions then locks in a profit whatever happens.
+
+use crate::conversions::implied_probabilities;
+use crate::{Odds, OddsError};
+use alloc::vec::Vec;
+
+/// A guaranteed-profit opportunity found by [`detect`].
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::arbitrage;
+/// use odds_converter::Odds;
+///
+/// let lines = [Odds::new_american(110), Odds::new_american(-105)];
+/// let opportunity = arbitrage::detect(&lines).unwrap().unwrap();
+/// assert!(opportunity.profit_margin > 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArbitrageOpportunity {
+    /// Sum of the implied probabilities of every outcome (below 1.0).
+    pub total_implied_probability: f64,
+    /// Guaranteed profit per unit staked, `1 / total_implied_probability - 1`.
+    pub profit_margin: f64,
+    /// Fraction of a unit bankroll to stake on each outcome, in input order.
+    ///
+    /// Each fraction is the outcome's implied probability divided by the total,
+    /// which makes the payout identical whichever outcome wins.
+    pub stake_fractions: Vec<f64>,
+}
+
+/// Detects an arbitrage opportunity among the best prices for each outcome.
+///
+/// # Arguments
+///
+/// * `outcomes` - The best available price for each mutually exclusive outcome,
+///   typically taken from several different books
+///
+/// # Returns
+///
+/// Returns `Ok(Some(ArbitrageOpportunity))` when the implied probabilities sum to
+/// less than 1.0 and `Ok(None)` otherwise. Fewer than two outcomes can never form
+/// an arbitrage. Any invalid price is returned as an `Err(OddsError)`.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::arbitrage;
+/// use odds_converter::Odds;
+///
+/// let standard_line = [Odds::new_american(-110), Odds::new_american(-110)];
+/// assert!(arbitrage::detect(&standard_line).unwrap().is_none());
+/// ```
+pub fn detect(outcomes: &[Odds]) -> Result<Option<ArbitrageOpportunity>, OddsError> {
+    if outcomes.is_empty() {
+        return Ok(None);
+    }
+
+    let probabilities = implied_probabilities(outcomes)?;
+    if probabilities.len() < 2 {
+        return Ok(None);
+    }
+
+    let total: f64 = probabilities.iter().sum();
+    if total >= 1.0 {
+        return Ok(None);
+    }
+
+    Ok(Some(ArbitrageOpportunity {
+        total_implied_probability: total,
+        profit_margin: 1.0 / total - 1.0,
+        stake_fractions: probabilities.iter().map(|p| p / total).collect(),
+    }))
+}
@@ -0,0 +1,47 @@
+//! Conversion helpers for many odds at once.
+//!
+//! These functions process whole collections of input, collecting every failure
+//! alongside the input that caused it instead of stopping at the first error.
+
+use crate::{Odds, OddsError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Parses every input string, separating the successes from the failures.
+///
+/// Each string is parsed as with [`str::parse`], so any format accepted by the
+/// [`FromStr`](core::str::FromStr) implementation of [`Odds`] is allowed. Both
+/// results keep the relative order of the inputs.
+///
+/// # Arguments
+///
+/// * `inputs` - The strings to parse
+///
+/// # Returns
+///
+/// Returns the successfully parsed odds, and each input that failed to parse paired
+/// with its error.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::batch;
+///
+/// let (odds, failures) = batch::parse_all(["+150", "abc", "2.5"]);
+/// assert_eq!(odds.len(), 2);
+/// assert_eq!(failures.len(), 1);
+/// assert_eq!(failures[0].0, "abc");
+/// ```
+pub fn parse_all<'a>(
+    inputs: impl IntoIterator<Item = &'a str>,
+) -> (Vec<Odds>, Vec<(String, OddsError)>) {
+    let mut parsed = Vec::new();
+    let mut failures = Vec::new();
+    for input in inputs {
+        match input.parse::<Odds>() {
+            Ok(odds) => parsed.push(odds),
+            Err(error) => failures.push((input.to_string(), error)),
+        }
+    }
+    (parsed, failures)
+}
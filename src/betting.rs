@@ -0,0 +1,413 @@
+//! Betting analytics for individual odds.
+//!
+//! This module contains calculations that combine a price with a stake or a
+//! bettor's own probability estimate, such as the risk profile of a single bet.
+
+use crate::validation::{validate_probability, validate_stake};
+use crate::{Odds, OddsError};
+use alloc::format;
+
+impl Odds {
+    /// Calculates the variance of a single bet's profit.
+    ///
+    /// A bet either wins `(decimal - 1) * stake` with probability `p` or loses the
+    /// stake with probability `1 - p`. As a two-point distribution its variance is
+    /// `p * (1 - p) * (decimal * stake)^2`, which is useful for risk-adjusted staking.
+    ///
+    /// # Arguments
+    ///
+    /// * `true_probability` - Your estimate of the win probability (between 0 and 1)
+    /// * `stake` - The amount wagered (must be non-negative)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let even_money = Odds::new_decimal(2.0);
+    /// assert_eq!(even_money.return_variance(0.5, 10.0).unwrap(), 100.0);
+    /// ```
+    pub fn return_variance(&self, true_probability: f64, stake: f64) -> Result<f64, OddsError> {
+        self.validate()?;
+        validate_probability(true_probability)?;
+        validate_stake(stake)?;
+
+        let decimal = self.to_decimal()?;
+        let spread = decimal * stake;
+        Ok(true_probability * (1.0 - true_probability) * spread * spread)
+    }
+
+    /// Calculates the implied-probability cost of buying points.
+    ///
+    /// Buying a half-point moves the line in your favour but shortens the price, for
+    /// example from -110 to -120. The cost is the increase in implied probability
+    /// when moving from this price to `new_odds`; a negative result means the new
+    /// price is longer (as when selling points).
+    ///
+    /// # Arguments
+    ///
+    /// * `new_odds` - The price offered after buying or selling points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let cost = Odds::new_american(-110)
+    ///     .point_buy_cost_probability(&Odds::new_american(-120))
+    ///     .unwrap();
+    /// assert!((cost - 0.0216).abs() < 0.0001);
+    /// ```
+    pub fn point_buy_cost_probability(&self, new_odds: &Odds) -> Result<f64, OddsError> {
+        self.validate()?;
+        new_odds.validate()?;
+
+        Ok(new_odds.implied_probability()? - self.implied_probability()?)
+    }
+
+    /// Calculates the expected value of a bet given your own win probability.
+    ///
+    /// The expected value is `p * profit - (1 - p) * stake`, where the profit is
+    /// `(decimal - 1) * stake`. A positive result means the bet is profitable in the
+    /// long run according to your probability estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `true_probability` - Your estimate of the win probability (in `(0, 1]`)
+    /// * `stake` - The amount wagered (must be non-negative)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the expected profit, or an `Err(OddsError)` if the
+    /// odds are invalid. A probability or stake outside its allowed range is reported
+    /// as `OddsError::ValueOutOfRange`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let odds = Odds::new_american(150);
+    /// assert_eq!(odds.expected_value(0.6, 100.0).unwrap(), 50.0);
+    /// ```
+    pub fn expected_value(&self, true_probability: f64, stake: f64) -> Result<f64, OddsError> {
+        self.validate()?;
+        if !(true_probability > 0.0 && true_probability <= 1.0) {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Probability must be greater than 0 and at most 1, got: {}",
+                true_probability
+            )));
+        }
+        validate_stake(stake).map_err(|_| {
+            OddsError::ValueOutOfRange(format!(
+                "Stake must be finite and non-negative, got: {}",
+                stake
+            ))
+        })?;
+
+        let profit = (self.to_decimal()? - 1.0) * stake;
+        Ok(true_probability * profit - (1.0 - true_probability) * stake)
+    }
+
+    /// Calculates the Kelly criterion stake as a fraction of bankroll.
+    ///
+    /// The Kelly fraction `f = (b * p - q) / b` maximizes long-run bankroll growth,
+    /// where `b = decimal - 1` is the net odds, `p` the true win probability and
+    /// `q = 1 - p`. Negative results mean the bet has no edge and are clamped to 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `true_probability` - Your estimate of the win probability (between 0 and 1)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let even_money = Odds::new_decimal(2.0);
+    /// assert!((even_money.kelly_fraction(0.6).unwrap() - 0.2).abs() < 1e-12);
+    /// assert_eq!(even_money.kelly_fraction(0.4).unwrap(), 0.0);
+    /// ```
+    pub fn kelly_fraction(&self, true_probability: f64) -> Result<f64, OddsError> {
+        self.validate()?;
+        validate_probability(true_probability)?;
+
+        let net_odds = self.to_decimal()? - 1.0;
+        if net_odds <= 0.0 {
+            return Ok(0.0);
+        }
+
+        let fraction = (net_odds * true_probability - (1.0 - true_probability)) / net_odds;
+        Ok(fraction.max(0.0))
+    }
+
+    /// Calculates a scaled ("fractional") Kelly stake.
+    ///
+    /// Many bettors stake a fixed multiple of the full Kelly fraction, such as half
+    /// Kelly (`multiplier = 0.5`), to reduce volatility at the cost of growth.
+    ///
+    /// # Arguments
+    ///
+    /// * `true_probability` - Your estimate of the win probability (between 0 and 1)
+    /// * `multiplier` - The multiple of full Kelly to stake (must be non-negative)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let half_kelly = Odds::new_decimal(2.0).kelly_fraction_scaled(0.6, 0.5).unwrap();
+    /// assert!((half_kelly - 0.1).abs() < 1e-12);
+    /// ```
+    pub fn kelly_fraction_scaled(
+        &self,
+        true_probability: f64,
+        multiplier: f64,
+    ) -> Result<f64, OddsError> {
+        if !(multiplier.is_finite() && multiplier >= 0.0) {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Kelly multiplier must be finite and non-negative, got: {}",
+                multiplier
+            )));
+        }
+
+        Ok(self.kelly_fraction(true_probability)? * multiplier)
+    }
+
+    /// Calculates the implied probability adjusted for in-play latency risk.
+    ///
+    /// Live prices can move before a bet is accepted. This uses a simple expected
+    /// cost model: with probability `adverse_move_prob` the price moves against you
+    /// by `adverse_magnitude` implied-probability points before the bet lands, so the
+    /// effective break-even probability becomes
+    /// `implied + adverse_move_prob * adverse_magnitude`, capped at 1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `adverse_move_prob` - Probability the line moves against you (0 to 1)
+    /// * `adverse_magnitude` - Size of the adverse move in probability points (0 to 1)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let odds = Odds::new_decimal(2.0);
+    /// let adjusted = odds.latency_adjusted_probability(0.2, 0.05).unwrap();
+    /// assert!((adjusted - 0.51).abs() < 1e-12);
+    /// ```
+    pub fn latency_adjusted_probability(
+        &self,
+        adverse_move_prob: f64,
+        adverse_magnitude: f64,
+    ) -> Result<f64, OddsError> {
+        self.validate()?;
+        validate_probability(adverse_move_prob)?;
+        if !adverse_magnitude.is_finite() {
+            return Err(OddsError::InfiniteOrNaN);
+        }
+        if !(0.0..=1.0).contains(&adverse_magnitude) {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Adverse move magnitude must be between 0 and 1, got: {}",
+                adverse_magnitude
+            )));
+        }
+
+        let implied = self.implied_probability()?;
+        Ok((implied + adverse_move_prob * adverse_magnitude).min(1.0))
+    }
+
+    /// Infers the posted price of the other side of a two-way market.
+    ///
+    /// Given this side's price and an assumed bookmaker hold, the opposite side's
+    /// implied probability is chosen so the two sides sum to `1.0 + hold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `hold` - The assumed margin as a fraction, e.g. 0.045 for 4.5% (must be >= 0)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Odds)` with the opposite side's decimal odds, or an
+    /// `Err(OddsError)` if the hold is negative or leaves no valid opposite price.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let opposite = Odds::new_decimal(2.0).infer_opposite_with_hold(0.0).unwrap();
+    /// assert_eq!(opposite.to_decimal().unwrap(), 2.0);
+    /// ```
+    pub fn infer_opposite_with_hold(&self, hold: f64) -> Result<Odds, OddsError> {
+        self.validate()?;
+        if !hold.is_finite() {
+            return Err(OddsError::InfiniteOrNaN);
+        }
+        if hold < 0.0 {
+            return Err(OddsError::NegativeValue(format!(
+                "Hold cannot be negative, got: {}",
+                hold
+            )));
+        }
+
+        let opposite = 1.0 + hold - self.implied_probability()?;
+        if opposite <= 0.0 || opposite > 1.0 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "A hold of {} leaves no valid opposite price for {}",
+                hold, self
+            )));
+        }
+
+        Odds::from_probability(opposite)
+    }
+
+    /// Calculates fair odds for this outcome not happening.
+    ///
+    /// The complement's implied probability is `1 - implied_probability`, as in a
+    /// two-way market with no margin. This is [`Odds::infer_opposite_with_hold`]
+    /// with a hold of zero.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Odds)` with the complement's decimal odds, or an `Err(OddsError)`
+    /// if the odds are invalid or imply a probability of 1 or more, which leaves no
+    /// probability for the complement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let complement = Odds::new_decimal(1.25).complement().unwrap();
+    /// assert!((complement.to_decimal().unwrap() - 5.0).abs() < 1e-9);
+    /// ```
+    pub fn complement(&self) -> Result<Odds, OddsError> {
+        self.validate()?;
+        let probability = self.implied_probability()?;
+        if probability <= 0.0 || probability >= 1.0 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Odds of {} imply a probability of {}, which has no complement",
+                self, probability
+            )));
+        }
+
+        Odds::from_probability(1.0 - probability)
+    }
+
+    /// Removes the bookmaker's margin from a single price.
+    ///
+    /// This uses the proportional (multiplicative) convention, the same one used by
+    /// [`Market::fair_probabilities`](crate::Market::fair_probabilities): the fair
+    /// probability is this price's implied probability divided by the market total,
+    /// `1 + overround`. When every outcome of the market is devigged this way, the
+    /// fair probabilities sum to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `overround` - The market's overround as a fraction, e.g. 0.045 for 4.5%
+    ///   (must be >= 0)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Odds)` with the fair decimal odds, or an `Err(OddsError)` if the
+    /// odds are invalid or the overround is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let fair = Odds::new_american(-110).remove_vig(0.047_619).unwrap();
+    /// assert!((fair.to_decimal().unwrap() - 2.0).abs() < 1e-4);
+    /// ```
+    pub fn remove_vig(&self, overround: f64) -> Result<Odds, OddsError> {
+        self.validate()?;
+        if !overround.is_finite() {
+            return Err(OddsError::InfiniteOrNaN);
+        }
+        if overround < 0.0 {
+            return Err(OddsError::NegativeValue(format!(
+                "Overround cannot be negative, got: {}",
+                overround
+            )));
+        }
+
+        Odds::from_probability(self.implied_probability()? / (1.0 + overround))
+    }
+
+    /// Adds a bookmaker's margin to a fair price.
+    ///
+    /// This is the inverse of [`Odds::remove_vig`]: the implied probability is
+    /// multiplied by `1 + margin` and the shortened decimal odds are returned. Applying
+    /// the same margin to every outcome of a fair market gives a book whose overround
+    /// equals `margin`.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The margin as a fraction, e.g. 0.05 for 5% (must be >= 0)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Odds)` with the quoted decimal odds, or an `Err(OddsError)` if the
+    /// odds are invalid, the margin is negative, or the inflated probability would
+    /// exceed 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let quoted = Odds::new_decimal(2.0).apply_margin(0.05).unwrap();
+    /// assert!((quoted.to_decimal().unwrap() - 1.905).abs() < 1e-3);
+    /// ```
+    pub fn apply_margin(&self, margin: f64) -> Result<Odds, OddsError> {
+        self.validate()?;
+        if !margin.is_finite() {
+            return Err(OddsError::InfiniteOrNaN);
+        }
+        if margin < 0.0 {
+            return Err(OddsError::NegativeValue(format!(
+                "Margin cannot be negative, got: {}",
+                margin
+            )));
+        }
+
+        Odds::from_probability(self.implied_probability()? * (1.0 + margin))
+    }
+
+    /// Calculates the stake on these odds that hedges an existing bet.
+    ///
+    /// `self` is the price available on the opposite side of the original bet. The
+    /// hedge stake makes the total return identical whichever side wins, by solving
+    /// `original_stake * original_decimal = hedge_stake * self_decimal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `original_stake` - The amount of the existing bet (must be non-negative)
+    /// * `original_odds` - The price the existing bet was placed at
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the hedge stake, or an `Err(OddsError)` if either
+    /// price is invalid or the stake is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// // Backed at 3.0 for 100; the other side is now 2.0
+    /// let hedge = Odds::new_decimal(2.0)
+    ///     .hedge_stake(100.0, &Odds::new_decimal(3.0))
+    ///     .unwrap();
+    /// assert_eq!(hedge, 150.0);
+    /// ```
+    pub fn hedge_stake(&self, original_stake: f64, original_odds: &Odds) -> Result<f64, OddsError> {
+        self.validate()?;
+        original_odds.validate()?;
+        validate_stake(original_stake)?;
+
+        Ok(original_stake * original_odds.to_decimal()? / self.to_decimal()?)
+    }
+}
@@ -0,0 +1,309 @@
+//! Comparison of odds across formats.
+//!
+//! This module contains helpers for comparing two prices by the payout they offer
+//! or the probability they imply, independent of the format each one is stored in.
+
+use crate::{math, Odds, OddsError};
+use alloc::format;
+
+/// Decimal odds differences at or below this size are treated as floating-point noise.
+const PAYOUT_COMPARISON_TOLERANCE: f64 = 1e-9;
+
+/// Implied probability tolerance used by [`Odds::approx_eq_default`].
+const DEFAULT_PROBABILITY_TOLERANCE: f64 = 1e-6;
+
+/// Implied probabilities this close to 0.5 are classified as even money.
+const EVEN_MONEY_TOLERANCE: f64 = 1e-9;
+
+/// Whether a price is on the favorite or the underdog side of even money.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Odds, OddsClass};
+///
+/// assert_eq!(Odds::new_american(-200).classification().unwrap(), OddsClass::Favorite);
+/// assert_eq!(Odds::new_decimal(2.0).classification().unwrap(), OddsClass::EvenMoney);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OddsClass {
+    /// Implied probability above 50%.
+    Favorite,
+    /// Implied probability below 50%.
+    Underdog,
+    /// Implied probability of exactly 50%.
+    EvenMoney,
+}
+
+/// Which way a price moved between two observations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MovementDirection {
+    /// The price got shorter: lower odds, a more favored outcome.
+    Shortened,
+    /// The price got longer: higher odds, a less favored outcome.
+    Drifted,
+    /// The price did not move.
+    Unchanged,
+}
+
+/// How far a price moved, as returned by [`Odds::movement`].
+///
+/// Deltas are the current value minus the previous value, so a shortening price has
+/// a negative `decimal_delta` and a positive `probability_delta`.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{MovementDirection, Odds};
+///
+/// let movement = Odds::new_decimal(2.5).movement(&Odds::new_decimal(2.0)).unwrap();
+/// assert_eq!(movement.direction, MovementDirection::Drifted);
+/// assert!((movement.decimal_delta - 0.5).abs() < 1e-9);
+/// assert!((movement.probability_delta + 0.1).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OddsMovement {
+    /// Change in decimal odds.
+    pub decimal_delta: f64,
+    /// Change in implied probability.
+    pub probability_delta: f64,
+    /// Whether the price shortened, drifted or stayed the same.
+    pub direction: MovementDirection,
+}
+
+impl Odds {
+    /// Returns true when these odds pay strictly more than `other`.
+    ///
+    /// Both prices are converted to decimal odds and compared, so prices in
+    /// different formats can be compared directly. Differences of 1e-9 or less are
+    /// ignored so that equivalent prices such as 3/2 and 2.5 never flap.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The price to compare against
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert!(Odds::new_american(150).is_better_than(&Odds::new_american(140)).unwrap());
+    /// assert!(!Odds::new_fractional(3, 2).is_better_than(&Odds::new_decimal(2.5)).unwrap());
+    /// ```
+    pub fn is_better_than(&self, other: &Odds) -> Result<bool, OddsError> {
+        Ok(self.to_decimal()? - other.to_decimal()? > PAYOUT_COMPARISON_TOLERANCE)
+    }
+
+    /// Returns true when both prices convert to the same decimal odds.
+    ///
+    /// The decimal equivalents must agree within 1e-9, so this only matches prices
+    /// that are exact conversions of each other, such as +150, 2.5 and 3/2.
+    ///
+    /// This is stricter than `==`. Equality rounds decimal odds to three places so
+    /// that it stays consistent with [`Hash`](core::hash::Hash), which makes a
+    /// rounded quote such as 1.909 equal to 10/11 (1.90909...). `equivalent` tells
+    /// those apart. Returns false if either price cannot be converted.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The price to compare against
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert!(Odds::new_american(150).equivalent(&Odds::new_fractional(3, 2)));
+    ///
+    /// let quoted = Odds::new_decimal(1.909);
+    /// let exact = Odds::new_fractional(10, 11);
+    /// assert_eq!(quoted, exact);
+    /// assert!(!quoted.equivalent(&exact));
+    /// ```
+    pub fn equivalent(&self, other: &Odds) -> bool {
+        match (self.to_decimal(), other.to_decimal()) {
+            (Ok(a), Ok(b)) => math::abs(a - b) <= PAYOUT_COMPARISON_TOLERANCE,
+            _ => false,
+        }
+    }
+
+    /// Returns true when two prices imply the same probability within `tolerance`.
+    ///
+    /// The implied probabilities of both prices are compared, so equivalent odds in
+    /// different formats (such as 10/11, 1.909 and -110) can be matched without
+    /// converting them by hand. Returns false if either price cannot be converted.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The price to compare against
+    /// * `tolerance` - The largest absolute difference in implied probability allowed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let fractional = Odds::new_fractional(10, 11);
+    /// assert!(fractional.approx_eq(&Odds::new_american(-110), 0.01));
+    /// assert!(!fractional.approx_eq(&Odds::new_decimal(2.1), 0.01));
+    /// ```
+    pub fn approx_eq(&self, other: &Odds, tolerance: f64) -> bool {
+        match (self.implied_probability(), other.implied_probability()) {
+            (Ok(a), Ok(b)) => math::abs(a - b) <= tolerance,
+            _ => false,
+        }
+    }
+
+    /// Returns true when two prices imply the same probability within 1e-6.
+    ///
+    /// This is [`Odds::approx_eq`] with a tolerance tight enough to treat exact
+    /// conversions (3/2, 2.5 and +150) as equal while telling apart adjacent lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert!(Odds::new_fractional(3, 2).approx_eq_default(&Odds::new_american(150)));
+    /// assert!(!Odds::new_american(-110).approx_eq_default(&Odds::new_american(-111)));
+    /// ```
+    pub fn approx_eq_default(&self, other: &Odds) -> bool {
+        self.approx_eq(other, DEFAULT_PROBABILITY_TOLERANCE)
+    }
+
+    /// Classifies the price as a favorite, an underdog or even money.
+    ///
+    /// Prices whose implied probability is within 1e-9 of 0.5 are even money;
+    /// otherwise the price is a favorite above 0.5 and an underdog below it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(OddsClass)`, or an `Err(OddsError)` if the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsClass};
+    ///
+    /// assert_eq!(Odds::new_american(200).classification().unwrap(), OddsClass::Underdog);
+    /// assert_eq!(Odds::new_fractional(1, 1).classification().unwrap(), OddsClass::EvenMoney);
+    /// ```
+    pub fn classification(&self) -> Result<OddsClass, OddsError> {
+        self.validate()?;
+        let probability = self.implied_probability()?;
+        Ok(if math::abs(probability - 0.5) <= EVEN_MONEY_TOLERANCE {
+            OddsClass::EvenMoney
+        } else if probability > 0.5 {
+            OddsClass::Favorite
+        } else {
+            OddsClass::Underdog
+        })
+    }
+
+    /// Returns true when the price is a favorite (implied probability above 50%).
+    ///
+    /// Returns false if the odds are invalid.
+    pub fn is_favorite(&self) -> bool {
+        matches!(self.classification(), Ok(OddsClass::Favorite))
+    }
+
+    /// Returns true when the price is an underdog (implied probability below 50%).
+    ///
+    /// Returns false if the odds are invalid.
+    pub fn is_underdog(&self) -> bool {
+        matches!(self.classification(), Ok(OddsClass::Underdog))
+    }
+
+    /// Returns true when the price is even money (implied probability of 50%).
+    ///
+    /// Returns false if the odds are invalid.
+    pub fn is_even(&self) -> bool {
+        matches!(self.classification(), Ok(OddsClass::EvenMoney))
+    }
+
+    /// Measures how far the price moved from a previous price.
+    ///
+    /// Both prices are converted to decimal odds and implied probability, so the
+    /// opening and current lines may be in different formats. Decimal moves of 1e-9
+    /// or less are reported as [`MovementDirection::Unchanged`].
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The earlier price, such as the opening line
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(OddsMovement)`, or an `Err(OddsError)` if either price is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{MovementDirection, Odds};
+    ///
+    /// let opening = Odds::new_american(-110);
+    /// let current = Odds::new_american(-130);
+    /// let movement = current.movement(&opening).unwrap();
+    /// assert_eq!(movement.direction, MovementDirection::Shortened);
+    /// assert!(movement.probability_delta > 0.0);
+    /// ```
+    pub fn movement(&self, previous: &Odds) -> Result<OddsMovement, OddsError> {
+        self.validate()?;
+        previous.validate()?;
+        let decimal_delta = self.to_decimal()? - previous.to_decimal()?;
+        let probability_delta = self.implied_probability()? - previous.implied_probability()?;
+        let direction = if math::abs(decimal_delta) <= PAYOUT_COMPARISON_TOLERANCE {
+            MovementDirection::Unchanged
+        } else if decimal_delta < 0.0 {
+            MovementDirection::Shortened
+        } else {
+            MovementDirection::Drifted
+        };
+
+        Ok(OddsMovement {
+            decimal_delta,
+            probability_delta,
+            direction,
+        })
+    }
+
+    /// Returns true when the implied probability moved by more than `threshold_prob`.
+    ///
+    /// This is a yes/no summary of [`Odds::movement`] for alert rules. Moves in
+    /// either direction count; only the absolute change in implied probability is
+    /// compared against the threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The earlier price
+    /// * `threshold_prob` - The probability change that counts as steam (must be
+    ///   strictly between 0 and 1)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(bool)`, or an `Err(OddsError)` if the threshold is out of range or
+    /// either price is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let previous = Odds::new_decimal(2.0);
+    /// assert!(Odds::new_decimal(1.5).is_steam_move(&previous, 0.1).unwrap());
+    /// assert!(!Odds::new_decimal(1.95).is_steam_move(&previous, 0.1).unwrap());
+    /// ```
+    pub fn is_steam_move(&self, previous: &Odds, threshold_prob: f64) -> Result<bool, OddsError> {
+        if !threshold_prob.is_finite() {
+            return Err(OddsError::InfiniteOrNaN);
+        }
+        if threshold_prob <= 0.0 || threshold_prob >= 1.0 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Steam threshold must be between 0 and 1, got: {}",
+                threshold_prob
+            )));
+        }
+
+        let movement = self.movement(previous)?;
+        Ok(math::abs(movement.probability_delta) > threshold_prob)
+    }
+}
@@ -3,8 +3,12 @@
 //! This module contains all the logic for converting between different odds formats
 //! and calculating implied probabilities.
 
+use crate::math;
+use crate::validation::validate_stake;
 use crate::{Odds, OddsError, OddsFormat};
-use num_integer::gcd;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
 
 /// Normalizes American odds to their standard representation.
 ///
@@ -19,7 +23,7 @@ use num_integer::gcd;
 /// Examples:
 /// - +50 becomes -200, +25 becomes -400
 /// - -50 becomes +200, -25 becomes +400
-pub(crate) fn normalize_american_odds(odds: i32) -> i32 {
+pub(crate) const fn normalize_american_odds(odds: i32) -> i32 {
     if odds > 0 && odds < 100 {
         // Convert positive odds 1-99 to equivalent negative odds
         // Formula: -(100 * 100) / positive_odds
@@ -33,6 +37,465 @@ pub(crate) fn normalize_american_odds(odds: i32) -> i32 {
     }
 }
 
+/// How fractional American odds are rounded to a whole number.
+///
+/// Used by [`Odds::to_american_with_rounding`]. [`Odds::to_american`] always uses
+/// [`RoundingMode::HalfUp`].
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Odds, RoundingMode};
+///
+/// let odds = Odds::new_decimal(2.125); // +112.5
+/// assert_eq!(odds.to_american_with_rounding(RoundingMode::HalfUp).unwrap(), 113);
+/// assert_eq!(odds.to_american_with_rounding(RoundingMode::HalfEven).unwrap(), 112);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Round to the nearest integer, with halves rounded away from zero.
+    #[default]
+    HalfUp,
+    /// Round to the nearest integer, with halves rounded to the even neighbor.
+    HalfEven,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+}
+
+/// Distance from a whole number within which [`RoundingMode::Floor`] and
+/// [`RoundingMode::Ceil`] treat a value as that whole number.
+///
+/// Converting an exact price such as 5/6 (-120) can produce -120.00000000000001,
+/// which would otherwise floor to -121.
+const WHOLE_NUMBER_TOLERANCE: f64 = 1e-9;
+
+impl RoundingMode {
+    /// Rounds `value` to a whole number using this mode.
+    fn apply(self, value: f64) -> f64 {
+        let nearest = math::round(value);
+        if math::abs(value - nearest) < WHOLE_NUMBER_TOLERANCE {
+            return nearest;
+        }
+
+        match self {
+            RoundingMode::HalfUp => math::round(value),
+            RoundingMode::HalfEven => {
+                let floor = math::floor(value);
+                if value - floor == 0.5 {
+                    if floor % 2.0 == 0.0 {
+                        floor
+                    } else {
+                        floor + 1.0
+                    }
+                } else {
+                    math::round(value)
+                }
+            }
+            RoundingMode::Floor => math::floor(value),
+            RoundingMode::Ceil => math::ceil(value),
+        }
+    }
+}
+
+/// Every representation of a price, as returned by [`Odds::to_all_formats`].
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::Odds;
+///
+/// let formats = Odds::new_american(150).to_all_formats().unwrap();
+/// assert_eq!(formats.decimal, 2.5);
+/// assert_eq!(formats.fractional, (3, 2));
+/// assert_eq!(formats.implied_probability, 0.4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllFormats {
+    /// American odds, as returned by [`Odds::to_american`].
+    pub american: i32,
+    /// Decimal odds, as returned by [`Odds::to_decimal`].
+    pub decimal: f64,
+    /// Fractional odds as `(numerator, denominator)`, as returned by
+    /// [`Odds::to_fractional`].
+    pub fractional: (u32, u32),
+    /// Implied probability, `1 / decimal`.
+    pub implied_probability: f64,
+}
+
+/// Odds as a reduced fraction labeled odds-on or odds-against, as returned by
+/// [`Odds::fractional_description`].
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::Odds;
+///
+/// let description = Odds::new_american(-200).fractional_description().unwrap();
+/// assert_eq!((description.numerator, description.denominator), (1, 2));
+/// assert!(description.is_odds_on);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FractionalDescription {
+    /// The profit side of the fraction, in lowest terms.
+    pub numerator: u32,
+    /// The stake side of the fraction, in lowest terms.
+    pub denominator: u32,
+    /// True when the fraction is below 1/1, meaning the selection is the favorite.
+    pub is_odds_on: bool,
+}
+
+/// Converts decimal odds to whole American odds, rounding with `mode`.
+///
+/// Prices of 2.0 and above become positive odds and shorter prices negative odds.
+/// Both branches pass through [`normalize_american_odds`], so the result never lies
+/// in the -99..+99 gap whichever way it was rounded.
+fn decimal_to_american(decimal: f64, mode: RoundingMode) -> i32 {
+    let american = if decimal >= 2.0 {
+        mode.apply((decimal - 1.0) * 100.0)
+    } else {
+        mode.apply(-100.0 / (decimal - 1.0))
+    };
+    normalize_american_odds(american as i32)
+}
+
+/// Decimal difference within which [`Odds::to_american_checked`] reports an exact
+/// conversion.
+const AMERICAN_ROUND_TRIP_TOLERANCE: f64 = 1e-9;
+
+/// Default tolerance used by [`Odds::to_fractional`] when approximating a fraction.
+pub const DEFAULT_FRACTION_TOLERANCE: f64 = 1e-4;
+
+/// Traditional UK bookmaker fractional prices, ordered from shortest to longest.
+///
+/// Bookmakers quote from this fixed ladder rather than arbitrary reduced fractions,
+/// which is why a 2.40 decimal price is shown as 11/8 rather than 7/5.
+pub(crate) const STANDARD_FRACTIONAL_LADDER: &[(u32, u32)] = &[
+    (1, 100),
+    (1, 66),
+    (1, 50),
+    (1, 40),
+    (1, 33),
+    (1, 25),
+    (1, 20),
+    (1, 16),
+    (1, 14),
+    (1, 12),
+    (1, 10),
+    (1, 9),
+    (1, 8),
+    (2, 15),
+    (1, 7),
+    (2, 13),
+    (1, 6),
+    (2, 11),
+    (1, 5),
+    (2, 9),
+    (1, 4),
+    (2, 7),
+    (3, 10),
+    (1, 3),
+    (4, 11),
+    (2, 5),
+    (4, 9),
+    (1, 2),
+    (8, 15),
+    (4, 7),
+    (8, 13),
+    (4, 6),
+    (8, 11),
+    (4, 5),
+    (5, 6),
+    (10, 11),
+    (1, 1),
+    (11, 10),
+    (6, 5),
+    (5, 4),
+    (11, 8),
+    (6, 4),
+    (13, 8),
+    (7, 4),
+    (15, 8),
+    (2, 1),
+    (9, 4),
+    (5, 2),
+    (11, 4),
+    (3, 1),
+    (10, 3),
+    (7, 2),
+    (4, 1),
+    (9, 2),
+    (5, 1),
+    (11, 2),
+    (6, 1),
+    (13, 2),
+    (7, 1),
+    (15, 2),
+    (8, 1),
+    (17, 2),
+    (9, 1),
+    (10, 1),
+    (11, 1),
+    (12, 1),
+    (14, 1),
+    (16, 1),
+    (18, 1),
+    (20, 1),
+    (25, 1),
+    (33, 1),
+    (40, 1),
+    (50, 1),
+    (66, 1),
+    (80, 1),
+    (100, 1),
+    (125, 1),
+    (150, 1),
+    (200, 1),
+    (250, 1),
+    (300, 1),
+    (400, 1),
+    (500, 1),
+    (1000, 1),
+];
+
+/// Standard American lines and their implied probabilities, ordered by line.
+///
+/// Covers -1000 to +1000 in the increments books typically post: steps of 5 up to
+/// 200, 10 up to 500 and 50 up to 1000, on both sides. Even money is listed once,
+/// as +100. Each probability is exactly what [`Odds::implied_probability`] returns
+/// for that line.
+const COMMON_AMERICAN_LINES: &[(i32, f64)] = &[
+    (-1000, 0.9090909090909091),
+    (-950, 0.9047619047619049),
+    (-900, 0.8999999999999999),
+    (-850, 0.8947368421052632),
+    (-800, 0.8888888888888888),
+    (-750, 0.8823529411764706),
+    (-700, 0.875),
+    (-650, 0.8666666666666667),
+    (-600, 0.8571428571428571),
+    (-550, 0.8461538461538461),
+    (-500, 0.8333333333333334),
+    (-490, 0.8305084745762712),
+    (-480, 0.8275862068965518),
+    (-470, 0.8245614035087718),
+    (-460, 0.8214285714285714),
+    (-450, 0.8181818181818181),
+    (-440, 0.8148148148148148),
+    (-430, 0.8113207547169812),
+    (-420, 0.8076923076923077),
+    (-410, 0.803921568627451),
+    (-400, 0.8),
+    (-390, 0.7959183673469388),
+    (-380, 0.7916666666666667),
+    (-370, 0.7872340425531915),
+    (-360, 0.782608695652174),
+    (-350, 0.7777777777777779),
+    (-340, 0.7727272727272727),
+    (-330, 0.7674418604651163),
+    (-320, 0.7619047619047619),
+    (-310, 0.7560975609756098),
+    (-300, 0.75),
+    (-290, 0.7435897435897436),
+    (-280, 0.7368421052631579),
+    (-270, 0.7297297297297298),
+    (-260, 0.7222222222222222),
+    (-250, 0.7142857142857143),
+    (-240, 0.7058823529411764),
+    (-230, 0.696969696969697),
+    (-220, 0.6875),
+    (-210, 0.6774193548387096),
+    (-200, 0.6666666666666666),
+    (-195, 0.6610169491525424),
+    (-190, 0.6551724137931035),
+    (-185, 0.6491228070175439),
+    (-180, 0.6428571428571428),
+    (-175, 0.6363636363636364),
+    (-170, 0.6296296296296295),
+    (-165, 0.6226415094339623),
+    (-160, 0.6153846153846154),
+    (-155, 0.607843137254902),
+    (-150, 0.6000000000000001),
+    (-145, 0.5918367346938775),
+    (-140, 0.5833333333333333),
+    (-135, 0.574468085106383),
+    (-130, 0.5652173913043479),
+    (-125, 0.5555555555555556),
+    (-120, 0.5454545454545454),
+    (-115, 0.5348837209302326),
+    (-110, 0.5238095238095238),
+    (-105, 0.5121951219512195),
+    (100, 0.5),
+    (105, 0.48780487804878053),
+    (110, 0.47619047619047616),
+    (115, 0.46511627906976744),
+    (120, 0.45454545454545453),
+    (125, 0.4444444444444444),
+    (130, 0.4347826086956522),
+    (135, 0.425531914893617),
+    (140, 0.4166666666666667),
+    (145, 0.4081632653061224),
+    (150, 0.4),
+    (155, 0.3921568627450981),
+    (160, 0.3846153846153846),
+    (165, 0.37735849056603776),
+    (170, 0.37037037037037035),
+    (175, 0.36363636363636365),
+    (180, 0.35714285714285715),
+    (185, 0.3508771929824561),
+    (190, 0.3448275862068966),
+    (195, 0.3389830508474576),
+    (200, 0.3333333333333333),
+    (210, 0.3225806451612903),
+    (220, 0.3125),
+    (230, 0.30303030303030304),
+    (240, 0.29411764705882354),
+    (250, 0.2857142857142857),
+    (260, 0.2777777777777778),
+    (270, 0.27027027027027023),
+    (280, 0.2631578947368421),
+    (290, 0.25641025641025644),
+    (300, 0.25),
+    (310, 0.24390243902439027),
+    (320, 0.23809523809523808),
+    (330, 0.23255813953488372),
+    (340, 0.22727272727272727),
+    (350, 0.2222222222222222),
+    (360, 0.2173913043478261),
+    (370, 0.2127659574468085),
+    (380, 0.20833333333333334),
+    (390, 0.2040816326530612),
+    (400, 0.2),
+    (410, 0.19607843137254904),
+    (420, 0.1923076923076923),
+    (430, 0.18867924528301888),
+    (440, 0.18518518518518517),
+    (450, 0.18181818181818182),
+    (460, 0.17857142857142858),
+    (470, 0.17543859649122806),
+    (480, 0.1724137931034483),
+    (490, 0.1694915254237288),
+    (500, 0.16666666666666666),
+    (550, 0.15384615384615385),
+    (600, 0.14285714285714285),
+    (650, 0.13333333333333333),
+    (700, 0.125),
+    (750, 0.11764705882352941),
+    (800, 0.1111111111111111),
+    (850, 0.10526315789473684),
+    (900, 0.1),
+    (950, 0.09523809523809523),
+    (1000, 0.09090909090909091),
+];
+
+/// Finds the fraction with the smallest denominator within `tolerance` of `value`.
+///
+/// This is a Stern-Brocot search between 0/1 and 1/0. Runs of consecutive moves in
+/// the same direction are taken in a single step, which is what makes the search
+/// equivalent to expanding the continued fraction of `value`. The search stops early
+/// if the next candidate would no longer fit in a `u32`.
+fn approximate_fraction(value: f64, tolerance: f64) -> (u32, u32) {
+    if value <= tolerance {
+        return (0, 1);
+    }
+
+    let (mut lower_num, mut lower_den) = (0u64, 1u64);
+    let (mut upper_num, mut upper_den) = (1u64, 0u64);
+    let mut best = (math::round(value) as u64, 1u64);
+
+    loop {
+        let num = lower_num + upper_num;
+        let den = lower_den + upper_den;
+        if num > u32::MAX as u64 || den > u32::MAX as u64 {
+            break;
+        }
+
+        let mediant = num as f64 / den as f64;
+        if math::abs(mediant - value) <= tolerance {
+            best = (num, den);
+            break;
+        }
+        if math::abs(mediant - value) < math::abs(best.0 as f64 / best.1 as f64 - value) {
+            best = (num, den);
+        }
+
+        if mediant < value {
+            // Move right as many times as possible while staying below the target band.
+            let target = value - tolerance;
+            let mut steps = step_count(
+                target * lower_den as f64 - lower_num as f64,
+                upper_num as f64 - target * upper_den as f64,
+            );
+            while steps > 1
+                && (lower_num + steps * upper_num) as f64
+                    >= target * (lower_den + steps * upper_den) as f64
+            {
+                steps -= 1;
+            }
+            lower_num += steps * upper_num;
+            lower_den += steps * upper_den;
+        } else {
+            // Move left as many times as possible while staying above the target band.
+            let target = value + tolerance;
+            let mut steps = step_count(
+                upper_num as f64 - target * upper_den as f64,
+                target * lower_den as f64 - lower_num as f64,
+            );
+            while steps > 1
+                && (upper_num + steps * lower_num) as f64
+                    <= target * (upper_den + steps * lower_den) as f64
+            {
+                steps -= 1;
+            }
+            upper_num += steps * lower_num;
+            upper_den += steps * lower_den;
+        }
+    }
+
+    (best.0 as u32, best.1 as u32)
+}
+
+/// Number of same-direction Stern-Brocot moves implied by `numerator / denominator`.
+fn step_count(numerator: f64, denominator: f64) -> u64 {
+    let steps = math::floor(numerator / denominator);
+    if steps.is_finite() && steps >= 1.0 {
+        steps.min(u32::MAX as f64) as u64
+    } else {
+        1
+    }
+}
+
+/// Validates a set of outcomes and returns the implied probability of each.
+///
+/// This is the shared entry point for market-level calculations, so an empty
+/// slice and invalid prices are reported the same way everywhere.
+pub(crate) fn implied_probabilities(odds: &[Odds]) -> Result<Vec<f64>, OddsError> {
+    if odds.is_empty() {
+        return Err(OddsError::ValueOutOfRange(
+            "Market must contain at least one outcome".to_string(),
+        ));
+    }
+
+    let mut probabilities = Vec::with_capacity(odds.len());
+    for outcome in odds {
+        outcome.validate()?;
+        probabilities.push(outcome.implied_probability()?);
+    }
+    Ok(probabilities)
+}
+
+/// Converts a set of mutually exclusive outcomes into vig-free probabilities.
+///
+/// Each outcome's implied probability is divided by the market total, so the
+/// returned probabilities sum to 1. Outcomes are checked as in
+/// [`implied_probabilities`].
+pub(crate) fn normalized_probabilities(odds: &[Odds]) -> Result<Vec<f64>, OddsError> {
+    let probabilities = implied_probabilities(odds)?;
+    let total: f64 = probabilities.iter().sum();
+    Ok(probabilities.into_iter().map(|p| p / total).collect())
+}
+
 impl Odds {
     /// Converts odds to American format.
     ///
@@ -56,14 +519,40 @@ impl Odds {
     /// assert_eq!(fractional_odds.to_american().unwrap(), -200);
     /// ```
     pub fn to_american(&self) -> Result<i32, OddsError> {
+        self.to_american_with_rounding(RoundingMode::HalfUp)
+    }
+
+    /// Converts odds to American format using the given rounding mode.
+    ///
+    /// Converting decimal or fractional odds usually produces a fractional American
+    /// price, which must be rounded to a whole number. Sportsbooks differ in how they
+    /// round, so this allows matching a particular book's convention. American odds
+    /// are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - How to round the American price to a whole number
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(i32)` containing the American odds value, or an `Err(OddsError)`
+    /// if the conversion fails due to invalid input values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, RoundingMode};
+    ///
+    /// let odds = Odds::new_decimal(1.85); // -117.6...
+    /// assert_eq!(odds.to_american_with_rounding(RoundingMode::Floor).unwrap(), -118);
+    /// assert_eq!(odds.to_american_with_rounding(RoundingMode::Ceil).unwrap(), -117);
+    /// ```
+    pub fn to_american_with_rounding(&self, mode: RoundingMode) -> Result<i32, OddsError> {
         match &self.format {
             OddsFormat::American(value) => Ok(*value),
             OddsFormat::Decimal(decimal) => {
-                if *decimal >= 2.0 {
-                    let american = ((decimal - 1.0) * 100.0).round() as i32;
-                    Ok(normalize_american_odds(american))
-                } else if *decimal > 1.0 {
-                    Ok((-100.0 / (decimal - 1.0)).round() as i32)
+                if *decimal > 1.0 {
+                    Ok(decimal_to_american(*decimal, mode))
                 } else {
                     Err(OddsError::InvalidDecimalOdds(format!(
                         "Decimal odds must be greater than 1.0, got: {}",
@@ -73,14 +562,92 @@ impl Odds {
             }
             OddsFormat::Fractional(num, den) => {
                 let decimal = (*num as f64) / (*den as f64) + 1.0;
-                if decimal >= 2.0 {
-                    let american = ((decimal - 1.0) * 100.0).round() as i32;
-                    Ok(normalize_american_odds(american))
-                } else {
-                    Ok((-100.0 / (decimal - 1.0)).round() as i32)
-                }
+                Ok(decimal_to_american(decimal, mode))
             }
+            OddsFormat::StartingPrice => Err(OddsError::StartingPriceUndetermined),
+        }
+    }
+
+    /// Converts odds to American format and reports whether the conversion is exact.
+    ///
+    /// Converting decimal or fractional odds to American usually requires rounding.
+    /// The returned flag is true when the rounded American price converts back to
+    /// the original decimal odds within 1e-9, so approximate prices can be marked
+    /// (for example with "≈") before being displayed.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok((i32, bool))` containing the American odds value and whether it is
+    /// exact, or an `Err(OddsError)` if the conversion fails due to invalid input
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_decimal(2.5).to_american_checked().unwrap(), (150, true));
+    /// assert_eq!(Odds::new_decimal(1.91).to_american_checked().unwrap(), (-110, false));
+    /// ```
+    pub fn to_american_checked(&self) -> Result<(i32, bool), OddsError> {
+        let american = self.to_american()?;
+        let decimal = self.to_decimal()?;
+        let round_trip = Odds::new_american(american).to_decimal()?;
+        Ok((
+            american,
+            math::abs(round_trip - decimal) <= AMERICAN_ROUND_TRIP_TOLERANCE,
+        ))
+    }
+
+    /// Converts to American odds rounded to the nearest multiple of `tick`.
+    ///
+    /// Sportsbooks move American lines in fixed increments such as 5 or 10 cents, so
+    /// prices computed from a model are usually rounded before being posted. The sign
+    /// is preserved, and a result that would land in the illegal -99..+99 gap is
+    /// pushed out to the nearest multiple of `tick` with a magnitude of at least 100.
+    ///
+    /// # Arguments
+    ///
+    /// * `tick` - The line increment (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Odds)` containing the rounded American odds, or an `Err(OddsError)`
+    /// if `tick` is not positive or the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let rounded = Odds::new_american(148).round_american_to_tick(5).unwrap();
+    /// assert_eq!(rounded.format(), &OddsFormat::American(150));
+    ///
+    /// let rounded = Odds::new_decimal(1.9).round_american_to_tick(10).unwrap();
+    /// assert_eq!(rounded.format(), &OddsFormat::American(-110));
+    /// ```
+    pub fn round_american_to_tick(&self, tick: i32) -> Result<Odds, OddsError> {
+        if tick <= 0 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Tick size must be positive, got: {}",
+                tick
+            )));
+        }
+        self.validate()?;
+
+        let american = self.to_american()?;
+        let tick = tick as i64;
+        let mut rounded = (math::round(american as f64 / tick as f64) as i64) * tick;
+        if rounded.abs() < 100 {
+            // Step away from zero to the first tick outside the gap
+            let ticks_to_legal = (100 + tick - 1) / tick;
+            rounded = american.signum() as i64 * ticks_to_legal * tick;
         }
+
+        let rounded = i32::try_from(rounded).map_err(|_| {
+            OddsError::ValueOutOfRange(format!("Rounded American odds overflow: {}", rounded))
+        })?;
+        Ok(Odds::new_american(rounded))
     }
 
     /// Converts odds to decimal format.
@@ -88,6 +655,11 @@ impl Odds {
     /// Decimal odds represent the total return (including original stake) for a unit bet.
     /// This is the most straightforward format for mathematical calculations.
     ///
+    /// Fractional parts are exactly representable as `f64`, so the result is the
+    /// correctly rounded value of `num / den + 1`. Whole-number prices such as 5000/1
+    /// convert exactly; other ratios carry the usual `f64` relative error of about
+    /// 1e-16.
+    ///
     /// # Returns
     ///
     /// Returns `Ok(f64)` containing the decimal odds value, or an `Err(OddsError)`
@@ -120,11 +692,145 @@ impl Odds {
             }
             OddsFormat::Fractional(num, den) => {
                 if *den == 0 {
-                    Err(OddsError::ZeroDenominator)
+                    return Err(OddsError::ZeroDenominator);
+                }
+                let decimal = (*num as f64) / (*den as f64) + 1.0;
+                if decimal.is_finite() {
+                    Ok(decimal)
                 } else {
-                    Ok((*num as f64) / (*den as f64) + 1.0)
+                    Err(OddsError::InfiniteOrNaN)
                 }
             }
+            OddsFormat::StartingPrice => Err(OddsError::StartingPriceUndetermined),
+        }
+    }
+
+    /// Converts odds to decimal format, falling back to `default` on failure.
+    ///
+    /// The odds are validated first, so invalid values that would still convert
+    /// (such as NaN decimal odds) also return `default`. This mirrors
+    /// `unwrap_or` for hot paths that don't want to handle a `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - The value to return when the odds are invalid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_american(150).to_decimal_or(0.0), 2.5);
+    /// assert_eq!(Odds::new_decimal(f64::NAN).to_decimal_or(0.0), 0.0);
+    /// ```
+    pub fn to_decimal_or(&self, default: f64) -> f64 {
+        self.validate()
+            .and_then(|_| self.to_decimal())
+            .unwrap_or(default)
+    }
+
+    /// Converts odds to American format, falling back to `default` on failure.
+    ///
+    /// Like [`Odds::to_decimal_or`], the odds are validated first.
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - The value to return when the odds are invalid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_decimal(2.5).to_american_or(0), 150);
+    /// assert_eq!(Odds::new_fractional(3, 0).to_american_or(0), 0);
+    /// ```
+    pub fn to_american_or(&self, default: i32) -> i32 {
+        self.validate()
+            .and_then(|_| self.to_american())
+            .unwrap_or(default)
+    }
+
+    /// Converts odds to decimal format, rounded to `places` decimal places.
+    ///
+    /// Halves round away from zero. The result never drops below 1.0, the smallest
+    /// valid decimal price. When `places` is too large for the scaled value to be
+    /// represented, the unrounded decimal is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `places` - Number of digits to keep after the decimal point
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the rounded decimal odds, or an `Err(OddsError)`
+    /// if the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let odds = Odds::new_decimal(1.909);
+    /// assert_eq!(odds.to_decimal_rounded(2).unwrap(), 1.91);
+    /// assert_eq!(odds.to_decimal_rounded(1).unwrap(), 1.9);
+    /// ```
+    pub fn to_decimal_rounded(&self, places: u32) -> Result<f64, OddsError> {
+        self.validate()?;
+        let decimal = self.to_decimal()?;
+        let scale = math::powf(10.0, places as f64);
+        let scaled = decimal * scale;
+        if !scaled.is_finite() {
+            return Ok(decimal);
+        }
+
+        Ok((math::round(scaled) / scale).max(1.0))
+    }
+
+    /// Returns the equivalent odds in decimal format.
+    ///
+    /// This is a canonical representation for comparing or storing odds that may
+    /// have arrived in different formats.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Odds)` holding `OddsFormat::Decimal`, or an `Err(OddsError)` if
+    /// the conversion to decimal fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let normalized = Odds::new_fractional(1, 4).normalized().unwrap();
+    /// assert_eq!(normalized.format(), &OddsFormat::Decimal(1.25));
+    /// ```
+    pub fn normalized(&self) -> Result<Odds, OddsError> {
+        Ok(Odds::new_decimal(self.to_decimal()?))
+    }
+
+    /// Reduces fractional odds to lowest terms.
+    ///
+    /// The numerator and denominator are divided by their greatest common divisor,
+    /// following the UK convention of quoting 6/4 as 3/2. Unlike the conversion
+    /// methods this keeps the fractional format. American and decimal odds, and
+    /// the invalid fraction 0/0, are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let reduced = Odds::new_fractional(6, 4).simplified();
+    /// assert_eq!(reduced.format(), &OddsFormat::Fractional(3, 2));
+    /// ```
+    pub fn simplified(&self) -> Odds {
+        match self.format {
+            OddsFormat::Fractional(num, den) => match num_integer::gcd(num, den) {
+                0 => *self,
+                divisor => Odds::new_fractional(num / divisor, den / divisor),
+            },
+            _ => *self,
         }
     }
 
@@ -134,6 +840,10 @@ impl Odds {
     /// contains (numerator, denominator) where numerator is the profit and
     /// denominator is the stake amount.
     ///
+    /// Non-fractional odds are approximated by the fraction with the smallest
+    /// denominator whose profit ratio lies within [`DEFAULT_FRACTION_TOLERANCE`]
+    /// of the exact value, so -110 becomes the familiar 10/11 rather than 909/500.
+    ///
     /// # Returns
     ///
     /// Returns `Ok((u32, u32))` containing the fractional odds as (numerator, denominator),
@@ -145,24 +855,209 @@ impl Odds {
     /// use odds_converter::Odds;
     ///
     /// let decimal_odds = Odds::new_decimal(2.5);
-    /// let (num, den) = decimal_odds.to_fractional().unwrap();
-    /// // Should be equivalent to 3:2 odds (1.5 profit ratio + 1)
-    /// assert_eq!((num as f64) / (den as f64) + 1.0, 2.5);
+    /// assert_eq!(decimal_odds.to_fractional().unwrap(), (3, 2));
+    ///
+    /// let american_odds = Odds::new_american(-110);
+    /// assert_eq!(american_odds.to_fractional().unwrap(), (10, 11));
     /// ```
     pub fn to_fractional(&self) -> Result<(u32, u32), OddsError> {
+        self.to_fractional_with_tolerance(DEFAULT_FRACTION_TOLERANCE)
+    }
+
+    /// Converts odds to fractional format using a custom approximation tolerance.
+    ///
+    /// Walks the Stern-Brocot tree (equivalently, the continued-fraction expansion)
+    /// of the profit ratio and returns the first fraction - which is also the one
+    /// with the smallest denominator - lying within `tolerance` of the exact value.
+    /// Odds already stored as fractions are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - Maximum absolute difference between the returned fraction and
+    ///   the exact profit ratio (must be finite and non-negative)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let odds = Odds::new_decimal(1.909);
+    /// assert_eq!(odds.to_fractional_with_tolerance(1e-4).unwrap(), (10, 11));
+    /// assert_eq!(odds.to_fractional_with_tolerance(1e-6).unwrap(), (909, 1000));
+    /// ```
+    pub fn to_fractional_with_tolerance(&self, tolerance: f64) -> Result<(u32, u32), OddsError> {
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Fraction tolerance must be finite and non-negative, got: {}",
+                tolerance
+            )));
+        }
+
         match &self.format {
             OddsFormat::Fractional(num, den) => Ok((*num, *den)),
             _ => {
                 let decimal = self.to_decimal()?;
-                let profit = decimal - 1.0;
+                if !decimal.is_finite() {
+                    return Err(OddsError::InfiniteOrNaN);
+                }
+                if decimal < 1.0 {
+                    return Err(OddsError::InvalidDecimalOdds(format!(
+                        "Decimal odds must be >= 1.0, got: {}",
+                        decimal
+                    )));
+                }
+
+                Ok(approximate_fraction(decimal - 1.0, tolerance))
+            }
+        }
+    }
+
+    /// Converts odds to a profit ratio over a stake of 1, as in "pays 2.5 to 1".
+    ///
+    /// The first value is the profit per unit staked (decimal odds minus one) and
+    /// the denominator is always 1, so 5/2 becomes `(2.5, 1)`. Odds shorter than
+    /// even money give a profit below 1, such as `(0.5, 1)` for 1/2. Fractional
+    /// odds are divided exactly rather than going through decimal odds.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok((f64, u32))` containing the profit and a denominator of 1, or an
+    /// `Err(OddsError)` if the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_decimal(6.0).to_fractional_to_one().unwrap(), (5.0, 1));
+    /// assert_eq!(Odds::new_fractional(5, 2).to_fractional_to_one().unwrap(), (2.5, 1));
+    /// assert_eq!(Odds::new_american(-200).to_fractional_to_one().unwrap(), (0.5, 1));
+    /// ```
+    pub fn to_fractional_to_one(&self) -> Result<(f64, u32), OddsError> {
+        self.validate()?;
+        let profit = match &self.format {
+            OddsFormat::Fractional(num, den) => (*num as f64) / (*den as f64),
+            _ => self.to_decimal()? - 1.0,
+        };
+
+        Ok((profit, 1))
+    }
+
+    /// Converts the odds to every format at once.
+    ///
+    /// This is convenient for displays that show all formats side by side. The odds
+    /// are validated once and converted to decimal once, and the decimal value is
+    /// reused for the American, fractional and probability calculations.
+    ///
+    /// Decimal odds of exactly 1.0 (such as `0/1`) pay no profit and have no
+    /// American equivalent, so they are rejected even though they pass
+    /// [`Odds::validate`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(AllFormats)` containing each representation, or an
+    /// `Err(OddsError)` if the odds are invalid or equal to decimal 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let formats = Odds::new_decimal(1.5).to_all_formats().unwrap();
+    /// assert_eq!(formats.american, -200);
+    /// assert_eq!(formats.fractional, (1, 2));
+    /// ```
+    pub fn to_all_formats(&self) -> Result<AllFormats, OddsError> {
+        self.validate()?;
+        let decimal = self.to_decimal()?;
+        if decimal <= 1.0 {
+            return Err(OddsError::InvalidDecimalOdds(format!(
+                "Decimal odds must be greater than 1.0 to convert to American, got: {}",
+                decimal
+            )));
+        }
+        let fractional = match &self.format {
+            OddsFormat::Fractional(num, den) => (*num, *den),
+            _ => approximate_fraction(decimal - 1.0, DEFAULT_FRACTION_TOLERANCE),
+        };
 
-                let denominator = 1000;
-                let numerator = (profit * denominator as f64).round() as u32;
+        Ok(AllFormats {
+            american: decimal_to_american(decimal, RoundingMode::HalfUp),
+            decimal,
+            fractional,
+            implied_probability: 1.0 / decimal,
+        })
+    }
 
-                let common_divisor = gcd(numerator, denominator);
-                Ok((numerator / common_divisor, denominator / common_divisor))
+    /// Describes the odds as a reduced fraction labeled odds-on or odds-against.
+    ///
+    /// Fractional odds are reduced to lowest terms as with [`Odds::simplified`], and
+    /// other formats are approximated as with [`Odds::to_fractional`]. The odds are
+    /// odds-on when the fraction is below 1/1 (the favorite, such as 1/2) and
+    /// odds-against otherwise. Even money (1/1) is not odds-on.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(FractionalDescription)`, or an `Err(OddsError)` if the odds are
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert!(Odds::new_fractional(1, 2).fractional_description().unwrap().is_odds_on);
+    /// assert!(!Odds::new_fractional(2, 1).fractional_description().unwrap().is_odds_on);
+    ///
+    /// let description = Odds::new_fractional(6, 4).fractional_description().unwrap();
+    /// assert_eq!((description.numerator, description.denominator), (3, 2));
+    /// ```
+    pub fn fractional_description(&self) -> Result<FractionalDescription, OddsError> {
+        self.validate()?;
+        let (numerator, denominator) = self.simplified().to_fractional()?;
+
+        Ok(FractionalDescription {
+            numerator,
+            denominator,
+            is_odds_on: numerator < denominator,
+        })
+    }
+
+    /// Converts odds to the nearest price on the traditional UK fractional ladder.
+    ///
+    /// Unlike [`Odds::to_fractional`], which returns the simplest fraction matching
+    /// the odds, this snaps to the closest standard bookmaker price by absolute
+    /// difference in decimal odds. Fractional odds are snapped as well, so an
+    /// unusual price such as 19/10 is shown as the conventional 15/8.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok((u32, u32))` containing the closest ladder price, or an
+    /// `Err(OddsError)` if the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_decimal(3.0).to_fractional_standard().unwrap(), (2, 1));
+    /// assert_eq!(Odds::new_decimal(1.91).to_fractional_standard().unwrap(), (10, 11));
+    /// ```
+    pub fn to_fractional_standard(&self) -> Result<(u32, u32), OddsError> {
+        self.validate()?;
+        let decimal = self.to_decimal()?;
+
+        let mut closest = STANDARD_FRACTIONAL_LADDER[0];
+        let mut closest_distance = f64::INFINITY;
+        for &(num, den) in STANDARD_FRACTIONAL_LADDER {
+            let distance = math::abs(num as f64 / den as f64 + 1.0 - decimal);
+            if distance < closest_distance {
+                closest = (num, den);
+                closest_distance = distance;
             }
         }
+
+        Ok(closest)
     }
 
     /// Calculates the implied probability from the odds.
@@ -191,4 +1086,272 @@ impl Odds {
         let decimal = self.to_decimal()?;
         Ok(1.0 / decimal)
     }
+
+    /// Calculates the implied probability in basis points (hundredths of a percent).
+    ///
+    /// The probability is scaled to 0–10000 and rounded to the nearest whole basis
+    /// point, so even money is exactly 5000. Integer results bucket and compare
+    /// without floating-point noise. The odds are validated first.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(u32)` containing the probability in basis points, or an
+    /// `Err(OddsError)` if the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_decimal(2.0).implied_probability_bps().unwrap(), 5000);
+    /// assert_eq!(Odds::new_american(-110).implied_probability_bps().unwrap(), 5238);
+    /// ```
+    pub fn implied_probability_bps(&self) -> Result<u32, OddsError> {
+        self.validate()?;
+        let probability = self.implied_probability()?;
+        Ok(math::round(probability * 10_000.0) as u32)
+    }
+
+    /// Calculates the true (vig-free) probability of each outcome in a market.
+    ///
+    /// The raw implied probabilities are summed and each is divided by the total,
+    /// so the results sum to 1. This is the same proportional devig as
+    /// [`Market::fair_probabilities`](crate::Market::fair_probabilities), for when
+    /// the prices are already in a slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `market` - The price of every mutually exclusive outcome
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vec<f64>)` with one probability per outcome, in input order, or
+    /// an `Err(OddsError)` if the slice is empty or any price is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let market = [Odds::new_american(-110), Odds::new_american(-110)];
+    /// let probabilities = Odds::true_probabilities(&market).unwrap();
+    /// assert!((probabilities[0] - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn true_probabilities(market: &[Odds]) -> Result<Vec<f64>, OddsError> {
+        normalized_probabilities(market)
+    }
+
+    /// Returns the fraction of bets that must win to break even long-term.
+    ///
+    /// At fair value, a bet that wins exactly this often neither gains nor loses
+    /// money. It is the same value as [`Odds::implied_probability`], named for
+    /// clarity in staking and record-keeping code.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the break-even win rate between 0 and 1, or an
+    /// `Err(OddsError)` if the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_decimal(4.0).break_even_fraction().unwrap(), 0.25);
+    /// ```
+    pub fn break_even_fraction(&self) -> Result<f64, OddsError> {
+        self.implied_probability()
+    }
+
+    /// Returns the win rate, as a percentage, needed to break even long-term.
+    ///
+    /// This is [`Odds::break_even_fraction`] multiplied by 100.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the break-even win rate between 0 and 100, or an
+    /// `Err(OddsError)` if the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let percentage = Odds::new_american(-110).break_even_percentage().unwrap();
+    /// assert!((percentage - 52.38).abs() < 0.01);
+    /// ```
+    pub fn break_even_percentage(&self) -> Result<f64, OddsError> {
+        Ok(self.break_even_fraction()? * 100.0)
+    }
+
+    /// Calculates the total return (stake plus profit) of a winning bet.
+    ///
+    /// # Arguments
+    ///
+    /// * `stake` - The amount wagered (must be non-negative)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing `decimal * stake`, or an `Err(OddsError)` if the
+    /// odds are invalid or the stake is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let odds = Odds::new_american(150);
+    /// assert_eq!(odds.payout(100.0).unwrap(), 250.0);
+    /// ```
+    pub fn payout(&self, stake: f64) -> Result<f64, OddsError> {
+        self.validate()?;
+        validate_stake(stake)?;
+        Ok(self.to_decimal()? * stake)
+    }
+
+    /// Calculates the profit (excluding the returned stake) of a winning bet.
+    ///
+    /// # Arguments
+    ///
+    /// * `stake` - The amount wagered (must be non-negative)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing `(decimal - 1) * stake`, or an `Err(OddsError)`
+    /// if the odds are invalid or the stake is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let odds = Odds::new_american(-200);
+    /// assert_eq!(odds.profit(200.0).unwrap(), 100.0);
+    /// ```
+    pub fn profit(&self, stake: f64) -> Result<f64, OddsError> {
+        self.validate()?;
+        validate_stake(stake)?;
+        Ok((self.to_decimal()? - 1.0) * stake)
+    }
+
+    /// Calculates the stake needed to win a target profit.
+    ///
+    /// This is the inverse of [`Odds::profit`]: `target_profit / (decimal - 1)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_profit` - The profit to win (must be non-negative)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the required stake, or an `Err(OddsError)` if the
+    /// odds are invalid, the target is negative, or the odds are 1.0 and so can
+    /// never return a profit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let odds = Odds::new_american(-200);
+    /// assert_eq!(odds.stake_for_profit(100.0).unwrap(), 200.0);
+    /// ```
+    pub fn stake_for_profit(&self, target_profit: f64) -> Result<f64, OddsError> {
+        self.validate()?;
+        if !target_profit.is_finite() {
+            return Err(OddsError::InfiniteOrNaN);
+        }
+        if target_profit < 0.0 {
+            return Err(OddsError::NegativeValue(format!(
+                "Target profit cannot be negative, got: {}",
+                target_profit
+            )));
+        }
+
+        let net_odds = self.to_decimal()? - 1.0;
+        if net_odds <= 0.0 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Odds of {} return no profit, so no stake can win {}",
+                self, target_profit
+            )));
+        }
+
+        Ok(target_profit / net_odds)
+    }
+}
+
+/// Returns a table of standard American lines and their implied probabilities.
+///
+/// The table covers -1000 to +1000 in typical posting increments (5 up to 200, 10
+/// up to 500, 50 up to 1000), ordered from -1000 to +1000, with even money listed
+/// once as +100. It is precomputed, which makes it suitable for autocomplete lists.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::common_lines;
+///
+/// let lines = common_lines();
+/// assert_eq!(lines.first().unwrap().0, -1000);
+/// assert_eq!(lines.last().unwrap().0, 1000);
+/// assert!(lines.iter().any(|&(american, _)| american == -110));
+/// ```
+pub fn common_lines() -> &'static [(i32, f64)] {
+    COMMON_AMERICAN_LINES
+}
+
+/// Looks up the implied probability of a standard American line.
+///
+/// Only lines in [`common_lines`] are found; -100 is treated as +100.
+///
+/// # Arguments
+///
+/// * `american` - The American line to look up
+///
+/// # Returns
+///
+/// Returns `Some(f64)` with the implied probability, or `None` if the line is not
+/// in the table.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::probability_for_standard_line;
+///
+/// assert!((probability_for_standard_line(-110).unwrap() - 0.5238).abs() < 1e-4);
+/// assert_eq!(probability_for_standard_line(-113), None);
+/// ```
+pub fn probability_for_standard_line(american: i32) -> Option<f64> {
+    let american = if american == -100 { 100 } else { american };
+    COMMON_AMERICAN_LINES
+        .binary_search_by_key(&american, |&(line, _)| line)
+        .ok()
+        .map(|index| COMMON_AMERICAN_LINES[index].1)
+}
+
+/// Converts odds to their decimal value, as with [`Odds::to_decimal`].
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::Odds;
+///
+/// let decimal = f64::try_from(Odds::new_american(150)).unwrap();
+/// assert_eq!(decimal, 2.5);
+/// ```
+impl TryFrom<Odds> for f64 {
+    type Error = OddsError;
+
+    fn try_from(odds: Odds) -> Result<Self, Self::Error> {
+        odds.to_decimal()
+    }
+}
+
+/// Converts odds to their decimal value, as with [`Odds::to_decimal`].
+impl TryFrom<&Odds> for f64 {
+    type Error = OddsError;
+
+    fn try_from(odds: &Odds) -> Result<Self, Self::Error> {
+        odds.to_decimal()
+    }
 }
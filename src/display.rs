@@ -3,12 +3,176 @@
 //! This module implements string formatting and parsing for odds, allowing easy
 //! conversion between odds and their string representations.
 
-use crate::{Odds, OddsError, OddsFormat};
-use std::fmt;
-use std::str::FromStr;
+use crate::{Odds, OddsError, OddsFormat, OddsFormatKind, ParseError};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::num::{IntErrorKind, ParseIntError};
+use core::str::FromStr;
 
+/// Words accepted by [`FromStr`] as shorthand for even money (1/1).
+///
+/// Matching is case-insensitive. "evens" and "evs" are common in the UK, while
+/// "even" and "ev" are also used by American books.
+const EVEN_MONEY_KEYWORDS: &[&str] = &["evens", "evs", "even", "ev"];
+
+/// Words accepted by [`FromStr`] as American pick'em odds (+100).
+///
+/// Matching is case-insensitive. American books display "PK" for an even line.
+const PICK_EM_KEYWORDS: &[&str] = &["pk", "pick"];
+
+/// Unit suffixes stripped by [`Odds::parse_american_lenient`].
+///
+/// Matching is case-insensitive. Any other trailing text is rejected.
+const UNIT_SUFFIXES: &[&str] = &["u", "unit", "units"];
+
+/// Formats odds compactly ("+150", "2.50", "3/2").
+///
+/// The alternate form (`{:#}`) labels the format for log and debug output, as in
+/// "American(+150)", "Decimal(2.50)" and "Fractional(3/2)". American even money
+/// (+100 or -100) is shown as "PK" in the alternate form, as American books
+/// display it. A starting price is shown as "SP" in both forms.
 impl fmt::Display for Odds {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            let label = match &self.format {
+                OddsFormat::American(100 | -100) => return write!(f, "PK"),
+                OddsFormat::StartingPrice => return self.fmt_compact(f),
+                OddsFormat::American(_) => "American",
+                OddsFormat::Decimal(_) => "Decimal",
+                OddsFormat::Fractional(_, _) => "Fractional",
+            };
+            write!(f, "{}(", label)?;
+            self.fmt_compact(f)?;
+            write!(f, ")")
+        } else {
+            self.fmt_compact(f)
+        }
+    }
+}
+
+impl FromStr for Odds {
+    type Err = OddsError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let s = input.trim();
+        parse_guessing_format(s)
+            .map_err(|error| map_offset(error, |offset| offset + offset_of(input, s)))
+    }
+}
+
+/// The format the [`FromStr`] heuristic picks for a string, before parsing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GuessedKind {
+    EvenMoney,
+    PickEm,
+    StartingPrice,
+    Percentage,
+    American,
+    Fractional,
+    Decimal,
+}
+
+/// Classifies trimmed input by its shape, in the order [`FromStr`] tries formats.
+///
+/// Shared by [`FromStr`] and [`Odds::detect_format`] so the two always agree.
+fn guess_kind(s: &str) -> GuessedKind {
+    let is_keyword = |keywords: &[&str]| keywords.iter().any(|k| s.eq_ignore_ascii_case(k));
+
+    // Even-money keywords ("evens", "EV", ...) map to 1/1
+    if is_keyword(EVEN_MONEY_KEYWORDS) {
+        return GuessedKind::EvenMoney;
+    }
+
+    // Pick'em keywords ("PK", "pick") map to American +100
+    if is_keyword(PICK_EM_KEYWORDS) {
+        return GuessedKind::PickEm;
+    }
+
+    // "SP" is a starting price, which has no value yet
+    if s.eq_ignore_ascii_case("sp") {
+        return GuessedKind::StartingPrice;
+    }
+
+    // Implied probability, e.g. "52.4%"
+    if s.ends_with('%') {
+        return GuessedKind::Percentage;
+    }
+
+    // American starts with + or - or is just a number. Well-formed digits too
+    // large for i32 fall through to decimal.
+    let signed = s.starts_with('+') || s.starts_with('-');
+    if (signed || (!s.is_empty() && s.chars().all(|c| c.is_ascii_digit())))
+        && (signed || check_american_grammar(s).is_err() || s.parse::<i32>().is_ok())
+    {
+        return GuessedKind::American;
+    }
+
+    if s.contains('/') {
+        return GuessedKind::Fractional;
+    }
+
+    GuessedKind::Decimal
+}
+
+/// Parses trimmed input as whichever odds format it looks like, for [`FromStr`].
+fn parse_guessing_format(s: &str) -> Result<Odds, OddsError> {
+    if s.is_empty() {
+        return Err(OddsError::ParseError(ParseError::new("Empty string")));
+    }
+
+    match guess_kind(s) {
+        GuessedKind::EvenMoney => Ok(Odds::new_fractional(1, 1)),
+        GuessedKind::PickEm => Ok(Odds::new_american(100)),
+        GuessedKind::StartingPrice => Ok(Odds::starting_price()),
+        GuessedKind::Percentage => {
+            let percentage = s[..s.len() - 1].trim();
+            let value = percentage.parse::<f64>().map_err(|error| {
+                OddsError::ParseError(ParseError::with_source(
+                    format!("Invalid percentage: '{}'", s),
+                    error,
+                ))
+            })?;
+            if !(value > 0.0 && value <= 100.0) {
+                return Err(OddsError::ValueOutOfRange(format!(
+                    "Percentage must be greater than 0% and at most 100%, got: {}",
+                    s
+                )));
+            }
+            let odds = Odds::from_probability(value / 100.0)?;
+            odds.validate()?;
+            Ok(odds)
+        }
+        GuessedKind::American => {
+            check_american_grammar(s)?;
+            let value = s
+                .parse::<i32>()
+                .map_err(|error| american_parse_error(s, error))?;
+            let odds = Odds::new_american(value);
+            odds.validate()?;
+            Ok(odds)
+        }
+        GuessedKind::Fractional => Odds::parse_fractional(s),
+        GuessedKind::Decimal => {
+            // Accept a comma as the decimal separator
+            let s = &normalize_decimal_comma(s)?;
+            let value = s.parse::<f64>().map_err(|_| {
+                OddsError::ParseError(ParseError::new(format!(
+                    "Unable to parse '{}' as any odds format",
+                    s
+                )))
+            })?;
+            let odds = Odds::new_decimal(value);
+            odds.validate()?;
+            Ok(odds)
+        }
+    }
+}
+
+impl Odds {
+    /// Writes the compact, unlabeled form of the odds.
+    fn fmt_compact(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.format {
             OddsFormat::American(value) => {
                 if *value > 0 {
@@ -19,84 +183,444 @@ impl fmt::Display for Odds {
             }
             OddsFormat::Decimal(value) => write!(f, "{:.2}", value),
             OddsFormat::Fractional(num, den) => write!(f, "{}/{}", num, den),
+            OddsFormat::StartingPrice => write!(f, "SP"),
         }
     }
-}
 
-impl FromStr for Odds {
-    type Err = OddsError;
+    /// Formats the odds as an "X to Y" phrase, such as "3 to 2".
+    ///
+    /// The odds are first converted with [`Odds::to_fractional`], whatever format
+    /// they are stored in, and reduced to lowest terms as with [`Odds::simplified`].
+    /// Even money is written as "even". Unlike the
+    /// [`Display`](fmt::Display) output ("3/2"), this reads naturally in prose.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(String)`, or an `Err(OddsError)` if the odds are invalid or
+    /// cannot be converted to fractional form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_decimal(2.5).as_ratio_string().unwrap(), "3 to 2");
+    /// assert_eq!(Odds::new_american(-110).as_ratio_string().unwrap(), "10 to 11");
+    /// assert_eq!(Odds::new_american(100).as_ratio_string().unwrap(), "even");
+    /// ```
+    pub fn as_ratio_string(&self) -> Result<String, OddsError> {
+        self.validate()?;
+        match self.simplified().to_fractional()? {
+            (1, 1) => Ok("even".to_string()),
+            (num, den) => Ok(format!("{} to {}", num, den)),
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
+    /// Formats the implied probability as a percentage with one decimal place.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(String)` such as `"52.4%"`, or an `Err(OddsError)` if the implied
+    /// probability cannot be calculated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert_eq!(Odds::new_american(-110).probability_string().unwrap(), "52.4%");
+    /// ```
+    pub fn probability_string(&self) -> Result<String, OddsError> {
+        self.probability_string_precision(1)
+    }
 
-        if s.is_empty() {
-            return Err(OddsError::ParseError("Empty string".to_string()));
+    /// Formats the implied probability as a percentage with `digits` decimal places.
+    ///
+    /// # Arguments
+    ///
+    /// * `digits` - Number of digits after the decimal point
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(String)` such as `"52.38%"`, or an `Err(OddsError)` if the implied
+    /// probability cannot be calculated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let odds = Odds::new_american(-110);
+    /// assert_eq!(odds.probability_string_precision(2).unwrap(), "52.38%");
+    /// assert_eq!(odds.probability_string_precision(0).unwrap(), "52%");
+    /// ```
+    pub fn probability_string_precision(&self, digits: usize) -> Result<String, OddsError> {
+        let percentage = self.implied_probability()? * 100.0;
+        Ok(format!("{:.*}%", digits, percentage))
+    }
+
+    /// Parses a string strictly as the given odds format.
+    ///
+    /// Unlike the general [`FromStr`] implementation, no format is guessed: the input
+    /// must be valid for `kind`, and parse errors name the expected format. This
+    /// dispatches to [`Odds::parse_american`], [`Odds::parse_decimal`] or
+    /// [`Odds::parse_fractional`].
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to parse
+    /// * `kind` - The format the string is expected to be in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat, OddsFormatKind};
+    ///
+    /// let odds = Odds::from_str_as("3/2", OddsFormatKind::Fractional).unwrap();
+    /// assert_eq!(odds.format(), &OddsFormat::Fractional(3, 2));
+    /// assert!(Odds::from_str_as("3/2", OddsFormatKind::Decimal).is_err());
+    /// ```
+    pub fn from_str_as(s: &str, kind: OddsFormatKind) -> Result<Odds, OddsError> {
+        match kind {
+            OddsFormatKind::American => Odds::parse_american(s),
+            OddsFormatKind::Decimal => Odds::parse_decimal(s),
+            OddsFormatKind::Fractional => Odds::parse_fractional(s),
         }
+    }
 
-        // Try American format first (starts with + or - or is just a number)
-        if s.starts_with('+') || s.starts_with('-') || s.chars().all(|c| c.is_ascii_digit()) {
-            if let Ok(value) = s.parse::<i32>() {
-                let odds = Odds::new_american(value);
-                odds.validate()?;
-                return Ok(odds);
-            } else if s.starts_with('+') || s.starts_with('-') {
-                return Err(OddsError::ParseError(format!(
-                    "Invalid American odds format: '{}'",
-                    s
-                )));
+    /// Reports which format the [`FromStr`] heuristic would parse a string as.
+    ///
+    /// Nothing is constructed or validated, so this can flag ambiguous input before
+    /// it is committed: "100" is detected as American, although it could also be
+    /// read as decimal odds of 100.0. Keywords are detected as their parsed format
+    /// ("evens" is fractional, "PK" is American) and percentages as decimal.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to inspect
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(OddsFormatKind)` with the format that would be chosen, or `None`
+    /// if the string is not in any odds format. A starting price ("SP") has no
+    /// format and also returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormatKind};
+    ///
+    /// assert_eq!(Odds::detect_format("100"), Some(OddsFormatKind::American));
+    /// assert_eq!(Odds::detect_format("2.5"), Some(OddsFormatKind::Decimal));
+    /// assert_eq!(Odds::detect_format("3/2"), Some(OddsFormatKind::Fractional));
+    /// assert_eq!(Odds::detect_format("xyz"), None);
+    /// ```
+    pub fn detect_format(s: &str) -> Option<OddsFormatKind> {
+        let s = s.trim();
+        match guess_kind(s) {
+            GuessedKind::EvenMoney => Some(OddsFormatKind::Fractional),
+            GuessedKind::PickEm => Some(OddsFormatKind::American),
+            GuessedKind::StartingPrice => None,
+            GuessedKind::Percentage => s[..s.len() - 1]
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .map(|_| OddsFormatKind::Decimal),
+            GuessedKind::American => (check_american_grammar(s).is_ok()
+                && s.parse::<i32>().is_ok())
+            .then_some(OddsFormatKind::American),
+            GuessedKind::Fractional => {
+                let (num, den) = s.split_once('/')?;
+                let is_part = |part: &str| {
+                    let part = part.trim();
+                    !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())
+                };
+                (is_part(num) && is_part(den)).then_some(OddsFormatKind::Fractional)
             }
+            GuessedKind::Decimal => normalize_decimal_comma(s)
+                .ok()
+                .and_then(|s| s.parse::<f64>().ok())
+                .map(|_| OddsFormatKind::Decimal),
         }
+    }
 
-        // Try fractional format (contains /)
-        if s.contains('/') {
-            let parts: Vec<&str> = s.split('/').collect();
-            if parts.len() != 2 {
-                return Err(OddsError::ParseError(format!(
-                    "Invalid fractional format, expected 'num/den': '{}'",
-                    s
-                )));
-            }
+    /// Parses a string strictly as American odds.
+    ///
+    /// Unlike the general [`FromStr`] implementation, which guesses the format, this
+    /// always interprets the input as a whole number of American odds. Values between
+    /// -99 and +99 are normalized as in [`Odds::new_american`].
+    ///
+    /// The accepted grammar is an optional single `+` or `-` followed by ASCII
+    /// digits, with no leading zero unless the value is just "0". Inputs such as
+    /// "+-150", "--200" and "007" are rejected with [`OddsError::ParseError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let odds = Odds::parse_american("100").unwrap();
+    /// assert_eq!(odds.format(), &OddsFormat::American(100));
+    /// assert!(Odds::parse_american("2.5").is_err());
+    /// ```
+    pub fn parse_american(input: &str) -> Result<Odds, OddsError> {
+        let s = input.trim();
+        let leading = offset_of(input, s);
+        check_american_grammar(s).map_err(|error| map_offset(error, |offset| offset + leading))?;
+        let value = s.parse::<i32>().map_err(|error| {
+            map_offset(american_parse_error(s, error), |offset| offset + leading)
+        })?;
 
-            let num_str = parts[0].trim();
-            let den_str = parts[1].trim();
+        let odds = Odds::new_american(value);
+        odds.validate()?;
+        Ok(odds)
+    }
 
-            if num_str.is_empty() || den_str.is_empty() {
-                return Err(OddsError::ParseError(
-                    "Empty numerator or denominator in fraction".to_string(),
-                ));
-            }
+    /// Parses American odds as written by data feeds, stripping common decorations.
+    ///
+    /// A trailing unit ("150u", "-110 units") is removed, and a value wrapped
+    /// in parentheses is read accounting-style as negative ("(200)" is -200). The
+    /// remaining text is then parsed with [`Odds::parse_american`]. The general
+    /// [`FromStr`] implementation does not accept these decorations.
+    ///
+    /// Only "u", "unit" and "units" are recognized as units, in any case. Other
+    /// trailing text such as a currency ("150 EUR") is an error rather than being
+    /// silently dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let negative = Odds::parse_american_lenient("(200)").unwrap();
+    /// assert_eq!(negative.format(), &OddsFormat::American(-200));
+    ///
+    /// let units = Odds::parse_american_lenient("150u").unwrap();
+    /// assert_eq!(units.format(), &OddsFormat::American(150));
+    /// ```
+    pub fn parse_american_lenient(input: &str) -> Result<Odds, OddsError> {
+        let s = input.trim();
+        let number = s.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        let suffix = &s[number.len()..];
+        if !suffix.is_empty()
+            && !UNIT_SUFFIXES
+                .iter()
+                .any(|unit| suffix.eq_ignore_ascii_case(unit))
+        {
+            return Err(OddsError::ParseError(
+                ParseError::new(format!("Unrecognized suffix '{}' in '{}'", suffix, s))
+                    .with_offset(offset_of(input, suffix)),
+            ));
+        }
+        let undecorated = number.trim_end();
 
-            match (num_str.parse::<u32>(), den_str.parse::<u32>()) {
-                (Ok(num), Ok(den)) => {
-                    let odds = Odds::new_fractional(num, den);
-                    odds.validate()?;
-                    return Ok(odds);
-                }
-                (Err(_), _) => {
-                    return Err(OddsError::ParseError(format!(
-                        "Invalid numerator: '{}'",
-                        num_str
-                    )))
-                }
-                (_, Err(_)) => {
-                    return Err(OddsError::ParseError(format!(
-                        "Invalid denominator: '{}'",
-                        den_str
-                    )))
+        match undecorated
+            .strip_prefix('(')
+            .and_then(|inner| inner.strip_suffix(')'))
+        {
+            Some(inner) => {
+                let inner = inner.trim();
+                if inner.starts_with('+') || inner.starts_with('-') {
+                    return Err(OddsError::ParseError(ParseError::new(format!(
+                        "Parenthesized American odds cannot carry a sign: '{}'",
+                        s
+                    ))));
                 }
+                // Offsets in "-{inner}" are one past the matching offset in `inner`
+                let start = offset_of(input, inner);
+                Odds::parse_american(&format!("-{}", inner))
+                    .map_err(|error| map_offset(error, |offset| start + offset.saturating_sub(1)))
             }
+            None => Odds::parse_american(undecorated).map_err(|error| {
+                map_offset(error, |offset| offset + offset_of(input, undecorated))
+            }),
         }
+    }
 
-        // Try decimal format
-        if let Ok(value) = s.parse::<f64>() {
-            let odds = Odds::new_decimal(value);
-            odds.validate()?;
-            return Ok(odds);
+    /// Parses a string strictly as decimal odds.
+    ///
+    /// A single comma is accepted as the decimal separator ("2,50"). Use this when
+    /// a whole number such as "100" should mean decimal odds of 100.0 rather than
+    /// American odds of +100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let odds = Odds::parse_decimal("100").unwrap();
+    /// assert_eq!(odds.format(), &OddsFormat::Decimal(100.0));
+    /// ```
+    pub fn parse_decimal(input: &str) -> Result<Odds, OddsError> {
+        let s = input.trim();
+        let value = normalize_decimal_comma(s)
+            .map_err(|error| map_offset(error, |offset| offset + offset_of(input, s)))?
+            .parse::<f64>()
+            .map_err(|error| {
+                OddsError::ParseError(ParseError::with_source(
+                    format!("Invalid decimal odds format: '{}'", s),
+                    error,
+                ))
+            })?;
+
+        let odds = Odds::new_decimal(value);
+        odds.validate()?;
+        Ok(odds)
+    }
+
+    /// Parses a string strictly as fractional odds in `num/den` form.
+    ///
+    /// Spaces around the slash are allowed ("3 / 2"), but each side must otherwise
+    /// consist only of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let odds = Odds::parse_fractional("10 / 11").unwrap();
+    /// assert_eq!(odds.format(), &OddsFormat::Fractional(10, 11));
+    /// assert!(Odds::parse_fractional("2.5").is_err());
+    /// ```
+    pub fn parse_fractional(input: &str) -> Result<Odds, OddsError> {
+        let s = input.trim();
+        let parts: Vec<&str> = s.split('/').collect();
+        if parts.len() != 2 {
+            let mut error = ParseError::new(format!(
+                "Invalid fractional format, expected 'num/den': '{}'",
+                s
+            ));
+            // Point at the second slash when there are too many
+            if let Some(extra) = parts.get(2) {
+                error = error.with_offset(offset_of(input, extra) - 1);
+            }
+            return Err(OddsError::ParseError(error));
         }
 
-        Err(OddsError::ParseError(format!(
-            "Unable to parse '{}' as any odds format",
-            s
-        )))
+        let num_str = parts[0].trim();
+        let den_str = parts[1].trim();
+
+        if num_str.is_empty() || den_str.is_empty() {
+            let empty = if num_str.is_empty() { num_str } else { den_str };
+            return Err(OddsError::ParseError(
+                ParseError::new("Empty numerator or denominator in fraction")
+                    .with_offset(offset_of(input, empty)),
+            ));
+        }
+
+        let num = parse_fraction_part(num_str, "numerator")
+            .map_err(|error| map_offset(error, |offset| offset + offset_of(input, num_str)))?;
+        let den = parse_fraction_part(den_str, "denominator")
+            .map_err(|error| map_offset(error, |offset| offset + offset_of(input, den_str)))?;
+
+        let odds = Odds::new_fractional(num, den);
+        odds.validate()?;
+        Ok(odds)
+    }
+}
+
+/// Rejects American odds tokens that `i32::from_str` would accept or misread.
+///
+/// The grammar is `[+-]?digits`, where the digits have no leading zero unless the
+/// value is exactly "0". This rejects doubled signs ("+-150", "--200") and
+/// zero-padded values ("007"), which would otherwise parse as 7 and be normalized
+/// to an unexpected price. Tokens that are not sign-and-digits at all are left for
+/// `i32::from_str` to report, so the numeric error is kept as the source.
+fn check_american_grammar(s: &str) -> Result<(), OddsError> {
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let doubled_sign = digits.starts_with(['+', '-']);
+    let leading_zero = digits.len() > 1 && digits.starts_with('0');
+    if doubled_sign || leading_zero {
+        return Err(OddsError::ParseError(
+            ParseError::new(format!("Invalid American odds format: '{}'", s))
+                .with_offset(s.len() - digits.len()),
+        ));
+    }
+    Ok(())
+}
+
+/// Converts a single decimal comma ("2,50") into a dot so it can be parsed as `f64`.
+///
+/// Strings with several commas, a mix of commas and dots, or a comma followed by
+/// exactly three digits (thousands grouping such as "1,000") are rejected rather
+/// than being reinterpreted as a different number.
+fn normalize_decimal_comma(s: &str) -> Result<String, OddsError> {
+    let Some(position) = s.find(',') else {
+        return Ok(s.to_string());
+    };
+
+    let fraction = &s[position + 1..];
+    if fraction.contains(',') || s.contains('.') {
+        return Err(OddsError::ParseError(
+            ParseError::new(format!("Ambiguous decimal separators in '{}'", s))
+                .with_offset(position),
+        ));
+    }
+    if fraction.len() == 3 && fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err(OddsError::ParseError(
+            ParseError::new(format!("Comma in '{}' looks like a thousands separator", s))
+                .with_offset(position),
+        ));
+    }
+
+    Ok(s.replacen(',', ".", 1))
+}
+
+/// Parses one side of a fraction, accepting only ASCII digits.
+///
+/// The caller trims surrounding whitespace, so any remaining space (as in "3 2")
+/// or sign character makes the token invalid. A token too large for `u32` is
+/// reported as [`OddsError::ValueOutOfRange`]; other numeric failures keep the
+/// underlying `ParseIntError` as the error source.
+fn parse_fraction_part(token: &str, part: &str) -> Result<u32, OddsError> {
+    let message = format!("Invalid {}: '{}'", part, token);
+    if let Some(position) = token.find(|c: char| !c.is_ascii_digit()) {
+        return Err(OddsError::ParseError(
+            ParseError::new(message).with_offset(position),
+        ));
+    }
+
+    token.parse::<u32>().map_err(|error| match error.kind() {
+        IntErrorKind::PosOverflow => OddsError::ValueOutOfRange(format!(
+            "Fractional {} '{}' exceeds the maximum of {}",
+            part,
+            token,
+            u32::MAX
+        )),
+        _ => OddsError::ParseError(ParseError::with_source(message, error)),
+    })
+}
+
+/// Builds the error for an American odds token that `i32::from_str` rejected.
+///
+/// The offset points at the first character after the sign that is not a digit,
+/// or just past the sign when no digits follow. Overflowing values are all digits
+/// and carry no offset.
+fn american_parse_error(s: &str, error: ParseIntError) -> OddsError {
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let start = s.len() - digits.len();
+    let mut parse_error =
+        ParseError::with_source(format!("Invalid American odds format: '{}'", s), error);
+    if digits.is_empty() {
+        parse_error = parse_error.with_offset(start);
+    } else if let Some(position) = digits.find(|c: char| !c.is_ascii_digit()) {
+        parse_error = parse_error.with_offset(start + position);
+    }
+    OddsError::ParseError(parse_error)
+}
+
+/// Returns the byte offset of `inner` within `outer`, which it must be a slice of.
+fn offset_of(outer: &str, inner: &str) -> usize {
+    inner.as_ptr() as usize - outer.as_ptr() as usize
+}
+
+/// Adjusts the offset of a parse error, for errors raised on part of the input.
+fn map_offset(error: OddsError, f: impl FnOnce(usize) -> usize) -> OddsError {
+    match error {
+        OddsError::ParseError(error) => match error.offset() {
+            Some(offset) => OddsError::ParseError(error.with_offset(f(offset))),
+            None => OddsError::ParseError(error),
+        },
+        other => other,
     }
 }
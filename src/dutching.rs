@@ -0,0 +1,47 @@
+//! Dutching: staking several outcomes for the same profit whichever wins.
+//!
+//! Backing every selection in proportion to its implied probability makes the
+//! return identical for each winner. When the selections cover the whole market
+//! and their implied probabilities sum to less than 1, this is the arbitrage
+//! allocation from [`arbitrage`](crate::arbitrage).
+
+use crate::conversions::normalized_probabilities;
+use crate::validation::validate_stake;
+use crate::{Odds, OddsError};
+use alloc::vec::Vec;
+
+/// Splits a total stake across outcomes so each winning outcome returns the same.
+///
+/// Each outcome is staked `total_stake * q_i / sum(q)`, where `q_i` is its implied
+/// probability. Whichever outcome wins, the return is `total_stake / sum(q)`.
+///
+/// # Arguments
+///
+/// * `odds` - The price of each outcome to back
+/// * `total_stake` - The amount to split between them (must be non-negative)
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<f64>)` with the stake for each outcome, in input order, or an
+/// `Err(OddsError)` if the slice is empty, the stake is negative, or any price is
+/// invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::dutching;
+/// use odds_converter::Odds;
+///
+/// let stakes = dutching::stakes(&[Odds::new_decimal(3.0), Odds::new_decimal(6.0)], 90.0)
+///     .unwrap();
+/// assert!((stakes[0] - 60.0).abs() < 1e-9);
+/// assert!((stakes[1] - 30.0).abs() < 1e-9);
+/// ```
+pub fn stakes(odds: &[Odds], total_stake: f64) -> Result<Vec<f64>, OddsError> {
+    validate_stake(total_stake)?;
+
+    Ok(normalized_probabilities(odds)?
+        .into_iter()
+        .map(|share| total_stake * share)
+        .collect())
+}
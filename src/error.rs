@@ -1,4 +1,7 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
 
 /// Errors that can occur when working with betting odds.
 ///
@@ -41,8 +44,9 @@ pub enum OddsError {
     /// Failed to parse odds from a string.
     ///
     /// This occurs when a string cannot be interpreted as any valid odds format,
-    /// or when the string format is malformed.
-    ParseError(String),
+    /// or when the string format is malformed. The [`ParseError`] carries the
+    /// message and, where available, the underlying numeric parsing error.
+    ParseError(ParseError),
 
     /// Odds value is outside the acceptable range.
     ///
@@ -66,6 +70,14 @@ pub enum OddsError {
     /// This occurs when decimal odds are infinite or not-a-number, which cannot
     /// represent valid betting odds.
     InfiniteOrNaN,
+
+    /// The odds are a starting price ("SP") that is not yet known.
+    ///
+    /// This occurs when converting or validating [`OddsFormat::StartingPrice`]
+    /// odds, which have no value until the event starts.
+    ///
+    /// [`OddsFormat::StartingPrice`]: crate::OddsFormat::StartingPrice
+    StartingPriceUndetermined,
 }
 
 impl fmt::Display for OddsError {
@@ -79,8 +91,112 @@ impl fmt::Display for OddsError {
             OddsError::ZeroDenominator => write!(f, "Denominator cannot be zero"),
             OddsError::NegativeValue(msg) => write!(f, "Negative value not allowed: {}", msg),
             OddsError::InfiniteOrNaN => write!(f, "Value must be finite and not NaN"),
+            OddsError::StartingPriceUndetermined => write!(f, "Starting price not yet determined"),
         }
     }
 }
 
-impl std::error::Error for OddsError {}
+impl Error for OddsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            OddsError::ParseError(error) => error.source(),
+            _ => None,
+        }
+    }
+}
+
+/// Details of a failure to parse odds from a string.
+///
+/// Displays as its message. When the failure was caused by a lower-level error,
+/// such as a [`ParseIntError`](core::num::ParseIntError) from an overflowing number, that error is
+/// kept as the [`source`](Error::source) so it can be inspected or logged.
+///
+/// Where the failure can be pinned to one place in the input, the byte
+/// [`offset`](ParseError::offset) of that place is recorded as well, for example to
+/// underline the bad character in a form.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Odds, OddsError};
+/// use std::error::Error;
+///
+/// let error = "+99999999999".parse::<Odds>().unwrap_err();
+/// assert!(matches!(error, OddsError::ParseError(_)));
+/// assert!(error.source().is_some());
+///
+/// let OddsError::ParseError(error) = "3/a2".parse::<Odds>().unwrap_err() else {
+///     unreachable!()
+/// };
+/// assert_eq!(error.offset(), Some(2));
+/// ```
+#[derive(Debug)]
+pub struct ParseError {
+    message: String,
+    source: Option<Box<dyn Error + Send + Sync>>,
+    offset: Option<usize>,
+}
+
+impl ParseError {
+    /// Creates a parse error with a message and no underlying cause.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            source: None,
+            offset: None,
+        }
+    }
+
+    /// Creates a parse error caused by a lower-level error.
+    pub fn with_source(
+        message: impl Into<String>,
+        source: impl Into<Box<dyn Error + Send + Sync>>,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            source: Some(source.into()),
+            offset: None,
+        }
+    }
+
+    /// Records the byte offset in the input where parsing failed.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Returns the human-readable description of the failure.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the byte offset in the input where parsing failed, if known.
+    ///
+    /// Offsets count from the start of the string passed to the parser, including
+    /// any leading whitespace. They are recorded for bad characters in American
+    /// odds and fractions, misplaced fraction slashes and ambiguous decimal commas.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+}
+
+impl PartialEq for ParseError {
+    /// Parse errors compare by message; the underlying cause and offset are ignored.
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn Error + 'static))
+    }
+}
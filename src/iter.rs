@@ -0,0 +1,130 @@
+//! Extension methods for iterators over odds.
+//!
+//! This module provides [`OddsIteratorExt`], which adds market-level summaries to
+//! any iterator yielding [`Odds`] or `&Odds`, so a collection can be summarized
+//! without first copying it into a slice.
+
+use crate::{Odds, OddsError};
+use alloc::string::ToString;
+use core::borrow::Borrow;
+
+/// Summary methods for iterators over odds.
+///
+/// Implemented for every iterator whose items are [`Odds`] or `&Odds`, so it works
+/// with both `odds.iter()` and `odds.into_iter()`. Each method consumes the
+/// iterator, validates every price, and returns an error for an empty iterator.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Odds, OddsIteratorExt};
+///
+/// let odds = vec![Odds::new_decimal(2.0), Odds::new_decimal(4.0)];
+/// assert_eq!(odds.iter().total_implied_probability().unwrap(), 0.75);
+/// assert_eq!(odds.iter().best_decimal().unwrap(), 4.0);
+/// assert_eq!(odds.into_iter().average_decimal().unwrap(), 3.0);
+/// ```
+pub trait OddsIteratorExt: Iterator + Sized {
+    /// Sums the implied probabilities of the odds.
+    ///
+    /// For the outcomes of a single market this is the book's total, where any
+    /// excess over 1.0 is the overround.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the total implied probability, or an
+    /// `Err(OddsError)` if the iterator is empty or any price is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsIteratorExt};
+    ///
+    /// let market = [Odds::new_american(-110), Odds::new_american(-110)];
+    /// let total = market.iter().total_implied_probability().unwrap();
+    /// assert!((total - 1.0476).abs() < 1e-4);
+    /// ```
+    fn total_implied_probability(self) -> Result<f64, OddsError>;
+
+    /// Returns the highest decimal odds, the price that pays the most.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the best decimal odds, or an `Err(OddsError)` if
+    /// the iterator is empty or any price is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsIteratorExt};
+    ///
+    /// let prices = [Odds::new_american(-110), Odds::new_fractional(5, 4)];
+    /// assert_eq!(prices.iter().best_decimal().unwrap(), 2.25);
+    /// ```
+    fn best_decimal(self) -> Result<f64, OddsError>;
+
+    /// Returns the arithmetic mean of the decimal odds.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the average decimal odds, or an
+    /// `Err(OddsError)` if the iterator is empty or any price is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsIteratorExt};
+    ///
+    /// let prices = [Odds::new_decimal(1.5), Odds::new_american(150)];
+    /// assert_eq!(prices.iter().average_decimal().unwrap(), 2.0);
+    /// ```
+    fn average_decimal(self) -> Result<f64, OddsError>;
+}
+
+impl<I> OddsIteratorExt for I
+where
+    I: Iterator,
+    I::Item: Borrow<Odds>,
+{
+    fn total_implied_probability(self) -> Result<f64, OddsError> {
+        let (total, _) = fold_decimals(self, 0.0, |total, decimal| total + 1.0 / decimal)?;
+        Ok(total)
+    }
+
+    fn best_decimal(self) -> Result<f64, OddsError> {
+        let (best, _) = fold_decimals(self, f64::NEG_INFINITY, f64::max)?;
+        Ok(best)
+    }
+
+    fn average_decimal(self) -> Result<f64, OddsError> {
+        let (total, count) = fold_decimals(self, 0.0, |total, decimal| total + decimal)?;
+        Ok(total / count as f64)
+    }
+}
+
+/// Validates each price and folds its decimal odds, returning the result and count.
+fn fold_decimals<I>(
+    odds: I,
+    init: f64,
+    mut f: impl FnMut(f64, f64) -> f64,
+) -> Result<(f64, usize), OddsError>
+where
+    I: Iterator,
+    I::Item: Borrow<Odds>,
+{
+    let mut accumulator = init;
+    let mut count = 0;
+    for price in odds {
+        let price = price.borrow();
+        price.validate()?;
+        accumulator = f(accumulator, price.to_decimal()?);
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(OddsError::ValueOutOfRange(
+            "At least one price is required".to_string(),
+        ));
+    }
+    Ok((accumulator, count))
+}
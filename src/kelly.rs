@@ -0,0 +1,110 @@
+//! Kelly staking across several outcomes of one event.
+//!
+//! [`Odds::kelly_fraction`] sizes a single bet. When several mutually exclusive
+//! outcomes of the same event are bet at once, the stakes interact: money placed on
+//! one outcome is lost whenever another wins. The functions here size all the bets
+//! together.
+
+use crate::validation::validate_probability;
+use crate::{Odds, OddsError};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Allowance for rounding when checking that the true probabilities sum to at most 1.
+const PROBABILITY_SUM_TOLERANCE: f64 = 1e-9;
+
+/// Calculates Kelly stakes for betting several mutually exclusive outcomes at once.
+///
+/// The stakes maximize the expected logarithm of wealth, using the algorithm of
+/// Smoczynski and Tomkins (2010). Outcomes are ranked by expected return
+/// `p * decimal`, and added to the betting set while their expected return exceeds
+/// the reserve rate `R = (1 - sum(p)) / (1 - sum(1 / decimal))` of the set so far.
+/// Each outcome in the set is then staked `p - R / decimal`, and the rest are
+/// staked 0. Outcomes are never added if their prices would make the set an
+/// arbitrage (implied probabilities summing to 1 or more).
+///
+/// # Arguments
+///
+/// * `odds` - The price of each outcome
+/// * `true_probs` - Your estimate of each outcome's probability, in the same order
+///   as `odds`. They may sum to less than 1 when some outcomes are not priced.
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<f64>)` with the fraction of bankroll to stake on each outcome, in
+/// input order, or an `Err(OddsError)` if the slices are empty or differ in length,
+/// a probability is outside `[0, 1]`, the probabilities sum to more than 1, or any
+/// price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::kelly::simultaneous_kelly;
+/// use odds_converter::Odds;
+///
+/// let odds = [Odds::new_decimal(2.2), Odds::new_decimal(1.7)];
+/// let stakes = simultaneous_kelly(&odds, &[0.5, 0.5]).unwrap();
+/// assert!(stakes[0] > 0.0);
+/// assert_eq!(stakes[1], 0.0);
+/// ```
+pub fn simultaneous_kelly(odds: &[Odds], true_probs: &[f64]) -> Result<Vec<f64>, OddsError> {
+    if odds.len() != true_probs.len() {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Expected one probability per price, got {} prices and {} probabilities",
+            odds.len(),
+            true_probs.len()
+        )));
+    }
+    if odds.is_empty() {
+        return Err(OddsError::ValueOutOfRange(
+            "At least one outcome is required".to_string(),
+        ));
+    }
+
+    let mut decimals = Vec::with_capacity(odds.len());
+    for (price, &probability) in odds.iter().zip(true_probs) {
+        price.validate()?;
+        validate_probability(probability)?;
+        decimals.push(price.to_decimal()?);
+    }
+
+    let total_probability: f64 = true_probs.iter().sum();
+    if total_probability > 1.0 + PROBABILITY_SUM_TOLERANCE {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "True probabilities must sum to at most 1, got: {}",
+            total_probability
+        )));
+    }
+
+    let mut ranked: Vec<usize> = (0..odds.len()).collect();
+    ranked.sort_by(|&a, &b| {
+        let return_a = true_probs[a] * decimals[a];
+        let return_b = true_probs[b] * decimals[b];
+        return_b.total_cmp(&return_a)
+    });
+
+    let mut reserve_rate = 1.0;
+    let mut selected_probability = 0.0;
+    let mut selected_implied = 0.0;
+    let mut selected = 0;
+    for &outcome in &ranked {
+        let implied = 1.0 / decimals[outcome];
+        if true_probs[outcome] * decimals[outcome] <= reserve_rate
+            || selected_implied + implied >= 1.0
+        {
+            break;
+        }
+        selected_probability += true_probs[outcome];
+        selected_implied += implied;
+        reserve_rate = (1.0 - selected_probability) / (1.0 - selected_implied);
+        selected += 1;
+    }
+
+    let mut stakes = vec![0.0; odds.len()];
+    for &outcome in &ranked[..selected] {
+        stakes[outcome] = (true_probs[outcome] - reserve_rate / decimals[outcome]).max(0.0);
+    }
+    Ok(stakes)
+}
@@ -0,0 +1,160 @@
+//! Serialization-agnostic structured representation of odds.
+//!
+//! This module converts odds to and from a flat list of named fields, so they can
+//! be fed into any encoder (JSON, a database row, a metrics label set) without
+//! depending on serde.
+
+use crate::{Odds, OddsError, OddsFormat, ParseError};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A single field value produced by [`Odds::to_key_value`].
+///
+/// String values borrow for `'a`, so fields decoded into owned `String`s can be
+/// passed to [`Odds::from_key_value`] without copying them into static strings.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Odds, OddsValue};
+///
+/// let fields = Odds::new_american(150).to_key_value();
+/// assert_eq!(fields[1], ("value", OddsValue::Int(150)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OddsValue<'a> {
+    /// A signed integer, used for American odds.
+    Int(i32),
+    /// A floating-point number, used for decimal odds.
+    Float(f64),
+    /// An unsigned integer, used for fractional numerators and denominators.
+    UInt(u32),
+    /// A string, used for the format name.
+    Str(&'a str),
+}
+
+impl Odds {
+    /// Converts the odds to a list of named fields.
+    ///
+    /// The first entry is always `("format", Str(..))` holding `"american"`,
+    /// `"decimal"`, `"fractional"` or `"starting_price"`. American and decimal odds
+    /// follow it with a `"value"` entry, and fractional odds with `"numerator"` and
+    /// `"denominator"`. A starting price has no further entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsValue};
+    ///
+    /// assert_eq!(
+    ///     Odds::new_fractional(3, 2).to_key_value(),
+    ///     vec![
+    ///         ("format", OddsValue::Str("fractional")),
+    ///         ("numerator", OddsValue::UInt(3)),
+    ///         ("denominator", OddsValue::UInt(2)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_key_value(&self) -> Vec<(&'static str, OddsValue<'static>)> {
+        match self.format {
+            OddsFormat::American(value) => vec![
+                ("format", OddsValue::Str("american")),
+                ("value", OddsValue::Int(value)),
+            ],
+            OddsFormat::Decimal(value) => vec![
+                ("format", OddsValue::Str("decimal")),
+                ("value", OddsValue::Float(value)),
+            ],
+            OddsFormat::Fractional(num, den) => vec![
+                ("format", OddsValue::Str("fractional")),
+                ("numerator", OddsValue::UInt(num)),
+                ("denominator", OddsValue::UInt(den)),
+            ],
+            OddsFormat::StartingPrice => vec![("format", OddsValue::Str("starting_price"))],
+        }
+    }
+
+    /// Reconstructs odds from fields produced by [`Odds::to_key_value`].
+    ///
+    /// Fields may appear in any order and unknown keys are ignored. Keys and string
+    /// values may borrow from decoded data. American odds are stored as given,
+    /// without normalization, so a round trip preserves the original value.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The named fields describing the odds
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Odds)` containing the validated odds, or an `Err(OddsError)` if a
+    /// required field is missing, has the wrong type, or the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsValue};
+    ///
+    /// let odds = Odds::from_key_value(&[
+    ///     ("format", OddsValue::Str("decimal")),
+    ///     ("value", OddsValue::Float(2.5)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(odds, Odds::new_decimal(2.5));
+    ///
+    /// // Strings decoded at runtime can be passed in directly
+    /// let format = String::from("american");
+    /// let odds = Odds::from_key_value(&[
+    ///     ("format", OddsValue::Str(&format)),
+    ///     ("value", OddsValue::Int(-110)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(odds, Odds::new_american(-110));
+    /// ```
+    pub fn from_key_value(fields: &[(&str, OddsValue<'_>)]) -> Result<Odds, OddsError> {
+        let odds = match field(fields, "format")? {
+            OddsValue::Str("american") => match field(fields, "value")? {
+                OddsValue::Int(value) => Odds::new_american_raw(value),
+                other => return Err(wrong_type("value", other)),
+            },
+            OddsValue::Str("decimal") => match field(fields, "value")? {
+                OddsValue::Float(value) => Odds::new_decimal(value),
+                other => return Err(wrong_type("value", other)),
+            },
+            OddsValue::Str("fractional") => {
+                match (field(fields, "numerator")?, field(fields, "denominator")?) {
+                    (OddsValue::UInt(num), OddsValue::UInt(den)) => Odds::new_fractional(num, den),
+                    (OddsValue::UInt(_), other) => return Err(wrong_type("denominator", other)),
+                    (other, _) => return Err(wrong_type("numerator", other)),
+                }
+            }
+            OddsValue::Str("starting_price") => return Ok(Odds::starting_price()),
+            other => {
+                return Err(OddsError::ParseError(ParseError::new(format!(
+                    "Unknown odds format: {:?}",
+                    other
+                ))))
+            }
+        };
+
+        odds.validate()?;
+        Ok(odds)
+    }
+}
+
+/// Looks up a required field by key.
+fn field<'a>(fields: &[(&str, OddsValue<'a>)], key: &str) -> Result<OddsValue<'a>, OddsError> {
+    fields
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| OddsError::ParseError(ParseError::new(format!("Missing '{}' field", key))))
+}
+
+/// Builds the error for a field holding the wrong kind of value.
+fn wrong_type(key: &str, value: OddsValue<'_>) -> OddsError {
+    OddsError::ParseError(ParseError::new(format!(
+        "Unexpected value for '{}' field: {:?}",
+        key, value
+    )))
+}
@@ -0,0 +1,59 @@
+//! Knockout competition pricing.
+//!
+//! This module contains helpers for pricing outcomes that span several matches,
+//! such as a team qualifying from a two-legged tie.
+
+use crate::conversions::normalized_probabilities;
+use crate::{Odds, OddsError};
+
+/// Calculates fair odds for a team to qualify from a two-legged tie.
+///
+/// Each leg's win/draw/loss prices are devigged independently, and the legs are
+/// treated as independent. The model is deliberately simplified: it looks only at
+/// match results, ignoring goal difference and away goals. A team qualifies by
+/// winning one leg without losing the other, and is eliminated by losing one leg
+/// without winning the other. Ties on results (a win each, or two draws) are
+/// settled by extra time and penalties, modelled as a coin flip.
+///
+/// # Arguments
+///
+/// * `leg1_win`, `leg1_draw`, `leg1_loss` - First-leg prices from the team's perspective
+/// * `leg2_win`, `leg2_draw`, `leg2_loss` - Second-leg prices from the team's perspective
+///
+/// # Returns
+///
+/// Returns `Ok(Odds)` containing fair decimal odds to qualify, or an
+/// `Err(OddsError)` if any of the six prices is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::knockout::two_leg_qualify_odds;
+/// use odds_converter::Odds;
+///
+/// let win = Odds::new_decimal(2.8);
+/// let draw = Odds::new_decimal(3.2);
+/// let loss = Odds::new_decimal(2.8);
+///
+/// let qualify = two_leg_qualify_odds(&win, &draw, &loss, &win, &draw, &loss).unwrap();
+/// assert!((qualify.to_decimal().unwrap() - 2.0).abs() < 1e-9);
+/// ```
+pub fn two_leg_qualify_odds(
+    leg1_win: &Odds,
+    leg1_draw: &Odds,
+    leg1_loss: &Odds,
+    leg2_win: &Odds,
+    leg2_draw: &Odds,
+    leg2_loss: &Odds,
+) -> Result<Odds, OddsError> {
+    let leg1 = normalized_probabilities(&[*leg1_win, *leg1_draw, *leg1_loss])?;
+    let leg2 = normalized_probabilities(&[*leg2_win, *leg2_draw, *leg2_loss])?;
+    let (win1, draw1, loss1) = (leg1[0], leg1[1], leg1[2]);
+    let (win2, draw2, loss2) = (leg2[0], leg2[1], leg2[2]);
+
+    let outright = win1 * win2 + win1 * draw2 + draw1 * win2;
+    let level = win1 * loss2 + loss1 * win2 + draw1 * draw2;
+    let qualify = outright + 0.5 * level;
+
+    Ok(Odds::new_decimal(1.0 / qualify))
+}
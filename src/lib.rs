@@ -23,16 +23,53 @@
 //! // Parse from strings
 //! let odds: Odds = "+150".parse().unwrap();
 //! ```
+//!
+//! # Features
+//!
+//! - `std` (enabled by default) - Use the standard library. Without it the crate is
+//!   `no_std` and only requires `alloc`, with floating-point math provided by `libm`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+pub mod analysis;
+pub mod arbitrage;
+pub mod batch;
+mod betting;
+mod comparison;
 mod conversions;
 mod display;
+pub mod dutching;
 mod error;
+mod iter;
+pub mod kelly;
+mod key_value;
+pub mod knockout;
+mod market;
+mod math;
+pub mod middle;
+mod normal;
+pub mod parlay;
+pub mod probability;
+pub mod racing;
+pub mod simulation;
+pub mod spread;
 mod types;
 mod validation;
 
 // Re-export public types
-pub use error::OddsError;
-pub use types::{Odds, OddsFormat};
+pub use comparison::{MovementDirection, OddsClass, OddsMovement};
+pub use conversions::{
+    common_lines, probability_for_standard_line, AllFormats, FractionalDescription, RoundingMode,
+    DEFAULT_FRACTION_TOLERANCE,
+};
+pub use error::{OddsError, ParseError};
+pub use iter::OddsIteratorExt;
+pub use key_value::OddsValue;
+pub use market::{Market, MarketOutcome};
+pub use types::{AmericanOdds, DecimalOdds, FractionalOdds, Odds, OddsFormat, OddsFormatKind};
+pub use validation::{ValidationLevel, Validator};
 
 #[cfg(test)]
 mod tests {
@@ -50,6 +87,46 @@ mod tests {
         assert_eq!(fractional.format(), &OddsFormat::Fractional(3, 2));
     }
 
+    #[test]
+    fn test_new_american_raw_skips_normalization() {
+        assert_eq!(
+            Odds::new_american_raw(50).format(),
+            &OddsFormat::American(50)
+        );
+        assert_eq!(Odds::new_american(50).format(), &OddsFormat::American(-200));
+        assert_eq!(
+            Odds::new_american_raw(-200).format(),
+            &OddsFormat::American(-200)
+        );
+
+        // Validation still catches truly invalid values
+        assert!(Odds::new_american_raw(0).validate().is_err());
+    }
+
+    #[test]
+    fn test_hash_and_eq_use_canonical_decimal() {
+        use std::collections::HashSet;
+
+        let mut lines = HashSet::new();
+        lines.insert(Odds::new_american(-110));
+        lines.insert(Odds::new_decimal(1.909));
+        lines.insert(Odds::new_fractional(10, 11));
+        assert_eq!(lines.len(), 1);
+
+        assert_eq!(Odds::new_american(150), Odds::new_decimal(2.5));
+        assert_eq!(Odds::new_fractional(6, 4), Odds::new_fractional(3, 2));
+        assert_ne!(Odds::new_decimal(1.909), Odds::new_decimal(1.91));
+
+        // Unconvertible odds are only equal to themselves
+        let nan = Odds::new_decimal(f64::NAN);
+        let copy = nan;
+        assert_eq!(nan, copy);
+        assert_ne!(Odds::new_fractional(1, 0), Odds::new_fractional(2, 0));
+        lines.insert(Odds::new_fractional(1, 0));
+        lines.insert(Odds::new_fractional(1, 0));
+        assert_eq!(lines.len(), 2);
+    }
+
     #[test]
     fn test_american_to_decimal_conversion() {
         let positive_american = Odds::new_american(150);
@@ -84,6 +161,27 @@ mod tests {
         assert_eq!(num as f64 / den as f64 + 1.0, 2.5);
     }
 
+    #[test]
+    fn test_fractional_approximation() {
+        assert_eq!(Odds::new_american(-110).to_fractional().unwrap(), (10, 11));
+        assert_eq!(Odds::new_decimal(1.909).to_fractional().unwrap(), (10, 11));
+        assert_eq!(Odds::new_decimal(2.5).to_fractional().unwrap(), (3, 2));
+        assert_eq!(Odds::new_decimal(2.0).to_fractional().unwrap(), (1, 1));
+        assert_eq!(Odds::new_decimal(1.0).to_fractional().unwrap(), (0, 1));
+        assert_eq!(Odds::new_american(-150).to_fractional().unwrap(), (2, 3));
+        assert_eq!(Odds::new_american(10000).to_fractional().unwrap(), (100, 1));
+        assert_eq!(Odds::new_decimal(1.01).to_fractional().unwrap(), (1, 100));
+
+        // Tighter tolerances fall back to larger denominators
+        let odds = Odds::new_decimal(1.909);
+        assert_eq!(
+            odds.to_fractional_with_tolerance(1e-6).unwrap(),
+            (909, 1000)
+        );
+        assert!(odds.to_fractional_with_tolerance(-1.0).is_err());
+        assert!(odds.to_fractional_with_tolerance(f64::NAN).is_err());
+    }
+
     #[test]
     fn test_implied_probability() {
         let decimal = Odds::new_decimal(2.0);
@@ -93,6 +191,1434 @@ mod tests {
         assert!((american.implied_probability().unwrap() - 0.5).abs() < 0.01);
     }
 
+    #[test]
+    fn test_fractional_standard_ladder() {
+        assert_eq!(
+            Odds::new_decimal(3.0).to_fractional_standard().unwrap(),
+            (2, 1)
+        );
+        assert_eq!(
+            Odds::new_decimal(1.91).to_fractional_standard().unwrap(),
+            (10, 11)
+        );
+        assert_eq!(
+            Odds::new_american(-110).to_fractional_standard().unwrap(),
+            (10, 11)
+        );
+        assert_eq!(
+            Odds::new_decimal(2.45).to_fractional_standard().unwrap(),
+            (6, 4)
+        );
+        assert_eq!(
+            Odds::new_fractional(19, 10)
+                .to_fractional_standard()
+                .unwrap(),
+            (15, 8)
+        );
+        assert_eq!(
+            Odds::new_decimal(1000.0).to_fractional_standard().unwrap(),
+            (1000, 1)
+        );
+        assert!(Odds::new_decimal(0.5).to_fractional_standard().is_err());
+    }
+
+    #[test]
+    fn test_payout_and_profit() {
+        let odds = Odds::new_american(-110);
+        assert!((odds.profit(110.0).unwrap() - 100.0).abs() < 1e-9);
+        assert!((odds.payout(110.0).unwrap() - 210.0).abs() < 1e-9);
+
+        let fractional = Odds::new_fractional(3, 2);
+        assert_eq!(fractional.payout(10.0).unwrap(), 25.0);
+        assert_eq!(fractional.profit(10.0).unwrap(), 15.0);
+        assert_eq!(fractional.profit(0.0).unwrap(), 0.0);
+
+        assert!(matches!(
+            odds.payout(-1.0),
+            Err(OddsError::NegativeValue(_))
+        ));
+        assert!(matches!(
+            odds.profit(-1.0),
+            Err(OddsError::NegativeValue(_))
+        ));
+        assert!(Odds::new_american(0).profit(100.0).is_err());
+    }
+
+    #[test]
+    fn test_return_variance() {
+        let even_money = Odds::new_american(100);
+        assert_eq!(even_money.return_variance(0.5, 100.0).unwrap(), 10000.0);
+
+        // A certain outcome carries no risk
+        assert_eq!(
+            Odds::new_decimal(3.0).return_variance(1.0, 50.0).unwrap(),
+            0.0
+        );
+
+        assert!(even_money.return_variance(1.5, 100.0).is_err());
+        assert!(matches!(
+            even_money.return_variance(0.5, -1.0),
+            Err(OddsError::NegativeValue(_))
+        ));
+        assert!(Odds::new_decimal(0.5).return_variance(0.5, 100.0).is_err());
+    }
+
+    #[test]
+    fn test_point_buy_cost_probability() {
+        let original = Odds::new_american(-110);
+        let bought = Odds::new_american(-120);
+        let cost = original.point_buy_cost_probability(&bought).unwrap();
+        assert!((cost - (120.0 / 220.0 - 110.0 / 210.0)).abs() < 1e-12);
+        assert!(cost > 0.0);
+
+        // Selling the point back recovers the same amount
+        let refund = bought.point_buy_cost_probability(&original).unwrap();
+        assert!((cost + refund).abs() < 1e-12);
+
+        assert!(original
+            .point_buy_cost_probability(&Odds::new_american(0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_expected_value() {
+        let odds = Odds::new_american(150);
+        assert!((odds.expected_value(0.6, 100.0).unwrap() - 50.0).abs() < 1e-9);
+
+        // A fair price has zero expected value
+        let fair = Odds::new_decimal(2.0);
+        assert_eq!(fair.expected_value(0.5, 100.0).unwrap(), 0.0);
+
+        assert!(matches!(
+            odds.expected_value(0.0, 100.0),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            odds.expected_value(1.1, 100.0),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            odds.expected_value(0.6, -5.0),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            odds.expected_value(0.6, f64::NAN),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_kelly_fraction() {
+        let even_money = Odds::new_decimal(2.0);
+        assert!((even_money.kelly_fraction(0.6).unwrap() - 0.2).abs() < 1e-12);
+
+        // No edge means no bet
+        assert_eq!(even_money.kelly_fraction(0.5).unwrap(), 0.0);
+        assert_eq!(even_money.kelly_fraction(0.3).unwrap(), 0.0);
+
+        // +150 at 50%: (1.5 * 0.5 - 0.5) / 1.5
+        let underdog = Odds::new_american(150);
+        assert!((underdog.kelly_fraction(0.5).unwrap() - 1.0 / 6.0).abs() < 1e-12);
+
+        let quarter = even_money.kelly_fraction_scaled(0.6, 0.25).unwrap();
+        assert!((quarter - 0.05).abs() < 1e-12);
+
+        assert!(even_money.kelly_fraction(-0.1).is_err());
+        assert!(even_money.kelly_fraction_scaled(0.6, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_latency_adjusted_probability() {
+        let odds = Odds::new_american(-110);
+        let raw = odds.implied_probability().unwrap();
+        assert_eq!(odds.latency_adjusted_probability(0.0, 0.1).unwrap(), raw);
+        assert_eq!(odds.latency_adjusted_probability(0.5, 0.0).unwrap(), raw);
+
+        let adjusted = odds.latency_adjusted_probability(0.3, 0.02).unwrap();
+        assert!((adjusted - (raw + 0.006)).abs() < 1e-12);
+
+        // Never exceeds certainty
+        let short = Odds::new_decimal(1.01);
+        assert_eq!(short.latency_adjusted_probability(1.0, 1.0).unwrap(), 1.0);
+
+        assert!(odds.latency_adjusted_probability(1.1, 0.1).is_err());
+        assert!(odds.latency_adjusted_probability(0.1, -0.1).is_err());
+        assert!(odds.latency_adjusted_probability(0.1, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_infer_opposite_with_hold() {
+        let odds = Odds::new_american(-110);
+        let opposite = odds.infer_opposite_with_hold(0.045).unwrap();
+        let total = odds.implied_probability().unwrap() + opposite.implied_probability().unwrap();
+        assert!((total - 1.045).abs() < 1e-12);
+
+        let american = opposite.to_american().unwrap();
+        assert!((-112..=-105).contains(&american), "got {}", american);
+
+        // The standard -110/-110 hold reproduces the same price
+        let standard = odds.infer_opposite_with_hold(220.0 / 210.0 - 1.0).unwrap();
+        assert_eq!(standard.to_american().unwrap(), -110);
+
+        assert!(matches!(
+            odds.infer_opposite_with_hold(-0.01),
+            Err(OddsError::NegativeValue(_))
+        ));
+        assert!(Odds::new_decimal(1.1).infer_opposite_with_hold(0.0).is_ok());
+        assert!(Odds::new_decimal(1.0)
+            .infer_opposite_with_hold(0.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_is_better_than() {
+        let plus_150 = Odds::new_american(150);
+        let plus_140 = Odds::new_american(140);
+        assert!(plus_150.is_better_than(&plus_140).unwrap());
+        assert!(!plus_140.is_better_than(&plus_150).unwrap());
+
+        let minus_110 = Odds::new_american(-110);
+        let minus_120 = Odds::new_american(-120);
+        assert!(minus_110.is_better_than(&minus_120).unwrap());
+        assert!(!minus_120.is_better_than(&minus_110).unwrap());
+
+        // Equivalent prices in different formats are not better than each other
+        let decimal = Odds::new_decimal(2.5);
+        assert!(!plus_150.is_better_than(&decimal).unwrap());
+        assert!(!decimal.is_better_than(&plus_150).unwrap());
+
+        assert!(Odds::new_american(0).is_better_than(&plus_150).is_err());
+        assert!(plus_150
+            .is_better_than(&Odds::new_fractional(1, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_round_american_to_tick() {
+        let rounded = Odds::new_american(148).round_american_to_tick(5).unwrap();
+        assert_eq!(rounded.format(), &OddsFormat::American(150));
+
+        let rounded = Odds::new_american(-113).round_american_to_tick(5).unwrap();
+        assert_eq!(rounded.format(), &OddsFormat::American(-115));
+
+        // Computed decimal prices are converted first
+        let rounded = Odds::new_decimal(2.37).round_american_to_tick(10).unwrap();
+        assert_eq!(rounded.format(), &OddsFormat::American(140));
+
+        // +140 to the nearest 300 would be 0, so it is pushed out to +300
+        let rounded = Odds::new_american(140).round_american_to_tick(300).unwrap();
+        assert_eq!(rounded.format(), &OddsFormat::American(300));
+
+        let rounded = Odds::new_american(-105)
+            .round_american_to_tick(250)
+            .unwrap();
+        assert_eq!(rounded.format(), &OddsFormat::American(-250));
+
+        assert!(Odds::new_american(150).round_american_to_tick(0).is_err());
+        assert!(Odds::new_american(150).round_american_to_tick(-5).is_err());
+        assert!(Odds::new_decimal(0.5).round_american_to_tick(5).is_err());
+    }
+
+    #[test]
+    fn test_const_constructors() {
+        const STANDARD_VIG: Odds = Odds::new_decimal(1.909);
+        const TEN_ELEVEN: Odds = Odds::new_fractional(10, 11);
+        const RAW_PLUS_FIFTY: Odds = Odds::new_american_raw(50);
+        const PLUS_FIFTY: Odds = Odds::new_american(50);
+        const TABLE: [Odds; 2] = [Odds::new_american(-110), Odds::new_decimal(2.5)];
+        static FORMAT: &OddsFormat = STANDARD_VIG.format();
+
+        assert_eq!(FORMAT, &OddsFormat::Decimal(1.909));
+        assert_eq!(TEN_ELEVEN.format(), &OddsFormat::Fractional(10, 11));
+        assert_eq!(RAW_PLUS_FIFTY.format(), &OddsFormat::American(50));
+        assert_eq!(PLUS_FIFTY.format(), &OddsFormat::American(-200));
+        assert_eq!(TABLE[0].format(), &OddsFormat::American(-110));
+        assert_eq!(TABLE[1].to_american().unwrap(), 150);
+    }
+
+    #[test]
+    fn test_break_even() {
+        let standard = Odds::new_american(-110);
+        let percentage = standard.break_even_percentage().unwrap();
+        assert!((percentage - 52.38).abs() < 0.01);
+        assert!((standard.break_even_fraction().unwrap() - 110.0 / 210.0).abs() < 1e-12);
+
+        assert_eq!(
+            Odds::new_fractional(1, 1).break_even_percentage().unwrap(),
+            50.0
+        );
+        assert!(Odds::new_fractional(1, 0).break_even_fraction().is_err());
+    }
+
+    #[test]
+    fn test_to_american_with_rounding() {
+        // 2.125 decimal is exactly +112.5 American
+        let boundary = Odds::new_decimal(2.125);
+        assert_eq!(boundary.to_american().unwrap(), 113);
+        assert_eq!(
+            boundary
+                .to_american_with_rounding(RoundingMode::HalfUp)
+                .unwrap(),
+            113
+        );
+        assert_eq!(
+            boundary
+                .to_american_with_rounding(RoundingMode::HalfEven)
+                .unwrap(),
+            112
+        );
+        // +137.5 rounds up to the even neighbor
+        assert_eq!(
+            Odds::new_decimal(2.375)
+                .to_american_with_rounding(RoundingMode::HalfEven)
+                .unwrap(),
+            138
+        );
+
+        let favorite = Odds::new_fractional(5, 6); // -120 exactly
+        for mode in [
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+        ] {
+            assert_eq!(favorite.to_american_with_rounding(mode).unwrap(), -120);
+        }
+
+        let between = Odds::new_decimal(2.456); // +145.6
+        assert_eq!(
+            between
+                .to_american_with_rounding(RoundingMode::Floor)
+                .unwrap(),
+            145
+        );
+        assert_eq!(
+            between
+                .to_american_with_rounding(RoundingMode::Ceil)
+                .unwrap(),
+            146
+        );
+        assert_eq!(
+            between
+                .to_american_with_rounding(RoundingMode::HalfEven)
+                .unwrap(),
+            146
+        );
+
+        assert_eq!(RoundingMode::default(), RoundingMode::HalfUp);
+        assert!(Odds::new_decimal(0.5)
+            .to_american_with_rounding(RoundingMode::Floor)
+            .is_err());
+    }
+
+    #[test]
+    fn test_stake_for_profit() {
+        assert_eq!(
+            Odds::new_american(-200).stake_for_profit(100.0).unwrap(),
+            200.0
+        );
+        assert_eq!(
+            Odds::new_american(150).stake_for_profit(150.0).unwrap(),
+            100.0
+        );
+        assert_eq!(
+            Odds::new_fractional(3, 2).stake_for_profit(0.0).unwrap(),
+            0.0
+        );
+
+        // Round trip with profit()
+        let odds = Odds::new_decimal(1.91);
+        let stake = odds.stake_for_profit(50.0).unwrap();
+        assert!((odds.profit(stake).unwrap() - 50.0).abs() < 1e-9);
+
+        assert!(matches!(
+            Odds::new_decimal(1.0).stake_for_profit(100.0),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            Odds::new_decimal(2.0).stake_for_profit(-10.0),
+            Err(OddsError::NegativeValue(_))
+        ));
+        assert!(Odds::new_decimal(2.0).stake_for_profit(f64::NAN).is_err());
+        assert!(Odds::new_decimal(0.5).stake_for_profit(10.0).is_err());
+    }
+
+    #[test]
+    fn test_to_all_formats() {
+        for odds in [
+            Odds::new_american(-110),
+            Odds::new_american(250),
+            Odds::new_american(-100_000),
+            Odds::new_decimal(1.8),
+            Odds::new_fractional(7, 4),
+        ] {
+            let formats = odds.to_all_formats().unwrap();
+            assert_eq!(formats.american, odds.to_american().unwrap());
+            assert_eq!(formats.decimal, odds.to_decimal().unwrap());
+            assert_eq!(formats.fractional, odds.to_fractional().unwrap());
+            assert_eq!(formats.implied_probability, 1.0 / formats.decimal);
+        }
+
+        assert!(Odds::new_decimal(0.5).to_all_formats().is_err());
+        assert!(Odds::new_american(0).to_all_formats().is_err());
+        assert!(Odds::new_fractional(1, 0).to_all_formats().is_err());
+
+        // Zero-profit odds validate but have no American equivalent
+        for zero_profit in [Odds::new_decimal(1.0), Odds::new_fractional(0, 1)] {
+            assert!(zero_profit.validate().is_ok());
+            assert!(matches!(
+                zero_profit.to_all_formats(),
+                Err(OddsError::InvalidDecimalOdds(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_remove_vig() {
+        let odds = Odds::new_decimal(1.8);
+        let fair = odds.remove_vig(0.045).unwrap();
+        let fair_decimal = fair.to_decimal().unwrap();
+        assert!(fair_decimal > 1.8);
+        assert!((fair_decimal - 1.8 * 1.045).abs() < 1e-12);
+
+        // Devigging both sides of a market matches the full market devig
+        let market = Market::from_odds(vec![Odds::new_american(-150), Odds::new_american(130)]);
+        let overround = market.overround().unwrap();
+        for (odds, expected) in market
+            .odds()
+            .iter()
+            .zip(market.fair_probabilities().unwrap())
+        {
+            let fair = odds.remove_vig(overround).unwrap();
+            assert!((fair.implied_probability().unwrap() - expected).abs() < 1e-12);
+        }
+
+        assert!((odds.remove_vig(0.0).unwrap().to_decimal().unwrap() - 1.8).abs() < 1e-12);
+        assert!(matches!(
+            odds.remove_vig(-0.01),
+            Err(OddsError::NegativeValue(_))
+        ));
+        assert!(odds.remove_vig(f64::INFINITY).is_err());
+        assert!(Odds::new_decimal(0.5).remove_vig(0.045).is_err());
+    }
+
+    #[test]
+    fn test_true_probabilities() {
+        let three_way = [
+            Odds::new_decimal(2.4),
+            Odds::new_fractional(9, 4),
+            Odds::new_american(290),
+        ];
+        let probabilities = Odds::true_probabilities(&three_way).unwrap();
+        assert_eq!(probabilities.len(), 3);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        // Each outcome keeps its share of the raw implied probability
+        let total: f64 = three_way
+            .iter()
+            .map(|odds| odds.implied_probability().unwrap())
+            .sum();
+        let first = three_way[0].implied_probability().unwrap() / total;
+        assert!((probabilities[0] - first).abs() < 1e-12);
+
+        assert!(Odds::true_probabilities(&[]).is_err());
+        assert!(
+            Odds::true_probabilities(&[Odds::new_decimal(2.0), Odds::new_american(0)]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_odds_are_copy() {
+        let original = Odds::new_american(-110);
+        let copy = original;
+        assert_eq!(original.to_american().unwrap(), -110);
+        assert_eq!(copy.to_american().unwrap(), -110);
+
+        let format = *original.format();
+        assert_eq!(format, OddsFormat::American(-110));
+
+        // Copies can be captured by value without cloning
+        let lines = [original; 3];
+        let payout = move |stake: f64| copy.payout(stake).unwrap();
+        assert_eq!(lines.len(), 3);
+        assert!((payout(110.0) - 210.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_try_from_odds_for_f64() {
+        let american: f64 = Odds::new_american(150).try_into().unwrap();
+        assert_eq!(american, 2.5);
+
+        let decimal: f64 = Odds::new_decimal(1.91).try_into().unwrap();
+        assert_eq!(decimal, 1.91);
+
+        let fractional = Odds::new_fractional(1, 4);
+        assert_eq!(f64::try_from(&fractional).unwrap(), 1.25);
+        assert_eq!(f64::try_from(fractional).unwrap(), 1.25);
+
+        assert_eq!(
+            f64::try_from(&Odds::new_fractional(1, 0)),
+            Err(OddsError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_hedge_stake() {
+        // Backed at 3.0 for $100, then hedged at 2.0
+        let original = Odds::new_decimal(3.0);
+        let hedge_price = Odds::new_decimal(2.0);
+        let hedge = hedge_price.hedge_stake(100.0, &original).unwrap();
+        assert_eq!(hedge, 150.0);
+
+        // The locked-in profit is the same whichever side wins
+        let total_staked = 100.0 + hedge;
+        let original_wins = original.payout(100.0).unwrap() - total_staked;
+        let hedge_wins = hedge_price.payout(hedge).unwrap() - total_staked;
+        assert!((original_wins - 50.0).abs() < 1e-9);
+        assert!((hedge_wins - 50.0).abs() < 1e-9);
+
+        // Mixed formats are converted first
+        let hedge = Odds::new_american(-150)
+            .hedge_stake(50.0, &Odds::new_fractional(4, 1))
+            .unwrap();
+        assert!((hedge - 150.0).abs() < 1e-9);
+
+        assert!(hedge_price.hedge_stake(-100.0, &original).is_err());
+        assert!(hedge_price
+            .hedge_stake(100.0, &Odds::new_decimal(0.5))
+            .is_err());
+        assert!(Odds::new_american(0).hedge_stake(100.0, &original).is_err());
+    }
+
+    #[test]
+    fn test_normalized() {
+        for odds in [
+            Odds::new_american(150),
+            Odds::new_decimal(2.5),
+            Odds::new_fractional(3, 2),
+        ] {
+            assert_eq!(
+                odds.normalized().unwrap().format(),
+                &OddsFormat::Decimal(2.5)
+            );
+        }
+
+        assert!(Odds::new_fractional(3, 0).normalized().is_err());
+    }
+
+    #[test]
+    fn test_fractional_overflow() {
+        // A 12-digit numerator does not fit in u32
+        match "999999999999/1".parse::<Odds>() {
+            Err(OddsError::ValueOutOfRange(msg)) => assert!(msg.contains("999999999999")),
+            other => panic!("expected ValueOutOfRange, got {:?}", other),
+        }
+        assert!(matches!(
+            Odds::parse_fractional("1/999999999999"),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+
+        // u32::MAX itself parses, but is rejected by range validation
+        let max = format!("{}/1", u32::MAX);
+        assert!(matches!(
+            max.parse::<Odds>(),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        let decimal = Odds::new_fractional(u32::MAX, 1).to_decimal().unwrap();
+        assert!(decimal.is_finite());
+        assert_eq!(decimal, u32::MAX as f64 + 1.0);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let fractional = Odds::new_fractional(10, 11);
+        let decimal = Odds::new_decimal(1.909);
+        let american = Odds::new_american(-110);
+
+        assert!(fractional.approx_eq(&decimal, 0.01));
+        assert!(decimal.approx_eq(&american, 0.01));
+        assert!(american.approx_eq(&fractional, 0.01));
+        assert!(!fractional.approx_eq(&Odds::new_decimal(2.0), 0.01));
+
+        assert!(fractional.approx_eq_default(&american));
+        assert!(!decimal.approx_eq_default(&american));
+
+        // Unconvertible odds never compare equal
+        let invalid = Odds::new_fractional(1, 0);
+        assert!(!invalid.approx_eq(&invalid, 1.0));
+    }
+
+    #[test]
+    fn test_parse_american_lenient() {
+        assert_eq!(
+            Odds::parse_american_lenient("(200)").unwrap().format(),
+            &OddsFormat::American(-200)
+        );
+        assert_eq!(
+            Odds::parse_american_lenient("150u").unwrap().format(),
+            &OddsFormat::American(150)
+        );
+        assert_eq!(
+            Odds::parse_american_lenient(" -110 units ")
+                .unwrap()
+                .format(),
+            &OddsFormat::American(-110)
+        );
+        assert_eq!(
+            Odds::parse_american_lenient("(150)u").unwrap().format(),
+            &OddsFormat::American(-150)
+        );
+
+        assert!(Odds::parse_american_lenient("(-200)").is_err());
+        assert!(Odds::parse_american_lenient("u").is_err());
+        assert!(Odds::parse_american_lenient("(0)").is_err());
+
+        // Only known units are stripped, in any case
+        assert_eq!(
+            Odds::parse_american_lenient("150 U").unwrap().format(),
+            &OddsFormat::American(150)
+        );
+        assert_eq!(
+            Odds::parse_american_lenient("-110Units").unwrap().format(),
+            &OddsFormat::American(-110)
+        );
+        assert!(Odds::parse_american_lenient("150 EUR").is_err());
+        assert!(Odds::parse_american_lenient("150xyz").is_err());
+        assert!(Odds::parse_american_lenient("150 uu").is_err());
+
+        // The general parser stays strict
+        assert!("150u".parse::<Odds>().is_err());
+        assert!("(200)".parse::<Odds>().is_err());
+    }
+
+    #[test]
+    fn test_classification() {
+        assert_eq!(
+            Odds::new_american(-200).classification().unwrap(),
+            OddsClass::Favorite
+        );
+        assert_eq!(
+            Odds::new_american(200).classification().unwrap(),
+            OddsClass::Underdog
+        );
+        for even in [
+            Odds::new_american(100),
+            Odds::new_american(-100),
+            Odds::new_decimal(2.0),
+        ] {
+            assert_eq!(even.classification().unwrap(), OddsClass::EvenMoney);
+            assert!(even.is_even());
+            assert!(!even.is_favorite() && !even.is_underdog());
+        }
+
+        assert!(Odds::new_american(-110).is_favorite());
+        assert!(Odds::new_fractional(11, 10).is_underdog());
+
+        for invalid in [
+            Odds::new_fractional(1, 0),
+            Odds::new_decimal(f64::NAN),
+            Odds::new_decimal(0.5),
+        ] {
+            assert!(invalid.classification().is_err());
+            assert!(!invalid.is_favorite() && !invalid.is_underdog() && !invalid.is_even());
+        }
+    }
+
+    #[test]
+    fn test_default_is_even_money() {
+        assert_eq!(Odds::default().implied_probability().unwrap(), 0.5);
+        assert_eq!(Odds::default(), Odds::new_fractional(1, 1));
+
+        #[derive(Default)]
+        struct Selection {
+            odds: Odds,
+        }
+        assert!(Selection::default().odds.is_even());
+    }
+
+    #[test]
+    fn test_to_american_checked() {
+        assert_eq!(
+            Odds::new_decimal(2.5).to_american_checked().unwrap(),
+            (150, true)
+        );
+        // 2.37 is exactly +137, while 2.371 has to be rounded
+        assert_eq!(
+            Odds::new_decimal(2.37).to_american_checked().unwrap(),
+            (137, true)
+        );
+        assert_eq!(
+            Odds::new_decimal(2.371).to_american_checked().unwrap(),
+            (137, false)
+        );
+        assert_eq!(
+            Odds::new_fractional(10, 11).to_american_checked().unwrap(),
+            (-110, true)
+        );
+        assert_eq!(
+            Odds::new_fractional(1, 3).to_american_checked().unwrap(),
+            (-300, true)
+        );
+        assert_eq!(
+            Odds::new_american(-110).to_american_checked().unwrap(),
+            (-110, true)
+        );
+        assert!(Odds::new_decimal(0.5).to_american_checked().is_err());
+    }
+
+    #[test]
+    fn test_probability_string() {
+        assert_eq!(
+            Odds::new_decimal(2.0).probability_string().unwrap(),
+            "50.0%"
+        );
+        assert_eq!(
+            Odds::new_american(-110).probability_string().unwrap(),
+            "52.4%"
+        );
+        assert_eq!(
+            Odds::new_fractional(1, 3)
+                .probability_string_precision(3)
+                .unwrap(),
+            "75.000%"
+        );
+        assert!(Odds::new_fractional(1, 0).probability_string().is_err());
+    }
+
+    #[test]
+    fn test_zero_profit_fractional() {
+        let zero_profit = Odds::new_fractional(0, 1);
+        assert_eq!(zero_profit.to_decimal().unwrap(), 1.0);
+        assert_eq!(zero_profit.implied_probability().unwrap(), 1.0);
+        assert!(zero_profit.validate().is_ok());
+        assert_eq!(zero_profit.to_string(), "0/1");
+        assert_eq!("0/1".parse::<Odds>().unwrap(), zero_profit);
+
+        // The denominator is still checked
+        assert!(matches!(
+            Odds::new_fractional(0, 0).validate(),
+            Err(OddsError::ZeroDenominator)
+        ));
+    }
+
+    #[test]
+    fn test_clamp_to_valid() {
+        assert_eq!(
+            Odds::new_decimal(200_000.0).clamp_to_valid().format(),
+            &OddsFormat::Decimal(100_001.0)
+        );
+        assert_eq!(
+            Odds::new_decimal(0.5).clamp_to_valid().format(),
+            &OddsFormat::Decimal(1.0)
+        );
+        assert_eq!(
+            Odds::new_decimal(f64::NAN).clamp_to_valid().format(),
+            &OddsFormat::Decimal(1.0)
+        );
+        assert_eq!(
+            Odds::new_american(20_000_000).clamp_to_valid().format(),
+            &OddsFormat::American(10_000_000)
+        );
+        assert_eq!(
+            Odds::new_american(-20_000_000).clamp_to_valid().format(),
+            &OddsFormat::American(-10_000_000)
+        );
+        assert_eq!(
+            Odds::new_fractional(200_000, 3).clamp_to_valid().format(),
+            &OddsFormat::Fractional(100_000, 3)
+        );
+
+        // Values already in range are unchanged and clamped values validate
+        let in_range = Odds::new_american(-110);
+        assert_eq!(in_range.clamp_to_valid().format(), in_range.format());
+        for odds in [
+            Odds::new_decimal(f64::INFINITY),
+            Odds::new_american(i32::MIN),
+            Odds::new_fractional(u32::MAX, u32::MAX),
+        ] {
+            assert!(odds.clamp_to_valid().validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_key_value_round_trip() {
+        for odds in [
+            Odds::new_american(150),
+            Odds::new_american(-110),
+            Odds::new_decimal(2.5),
+            Odds::new_fractional(10, 11),
+            Odds::new_fractional(0, 1),
+        ] {
+            let fields = odds.to_key_value();
+            let restored = Odds::from_key_value(&fields).unwrap();
+            assert_eq!(restored.format(), odds.format());
+        }
+
+        assert_eq!(
+            Odds::new_american(150).to_key_value(),
+            vec![
+                ("format", OddsValue::Str("american")),
+                ("value", OddsValue::Int(150)),
+            ]
+        );
+
+        // Reconstruction validates and reports missing or mistyped fields
+        assert!(Odds::from_key_value(&Odds::new_decimal(0.5).to_key_value()).is_err());
+        assert!(Odds::from_key_value(&[("format", OddsValue::Str("decimal"))]).is_err());
+        assert!(Odds::from_key_value(&[
+            ("format", OddsValue::Str("american")),
+            ("value", OddsValue::Float(150.0)),
+        ])
+        .is_err());
+        assert!(Odds::from_key_value(&[("format", OddsValue::Str("moneyline"))]).is_err());
+
+        // Keys and string values can borrow from owned, decoded data
+        let decoded = [(String::from("format"), String::from("fractional"))];
+        let mut fields: Vec<(&str, OddsValue)> = decoded
+            .iter()
+            .map(|(key, value)| (key.as_str(), OddsValue::Str(value)))
+            .collect();
+        fields.push(("numerator", OddsValue::UInt(5)));
+        fields.push(("denominator", OddsValue::UInt(2)));
+        assert_eq!(
+            Odds::from_key_value(&fields).unwrap().format(),
+            &OddsFormat::Fractional(5, 2)
+        );
+    }
+
+    #[test]
+    fn test_movement() {
+        let opening = Odds::new_decimal(2.0);
+        let current = Odds::new_decimal(1.8);
+        let movement = current.movement(&opening).unwrap();
+        assert_eq!(movement.direction, MovementDirection::Shortened);
+        assert!((movement.decimal_delta + 0.2).abs() < 1e-9);
+        assert!((movement.probability_delta - (1.0 / 1.8 - 0.5)).abs() < 1e-9);
+        assert!(movement.probability_delta > 0.0);
+
+        let drift = opening.movement(&current).unwrap();
+        assert_eq!(drift.direction, MovementDirection::Drifted);
+        assert!(drift.probability_delta < 0.0);
+
+        // Equivalent prices in different formats have not moved
+        let unchanged = Odds::new_fractional(3, 2)
+            .movement(&Odds::new_american(150))
+            .unwrap();
+        assert_eq!(unchanged.direction, MovementDirection::Unchanged);
+
+        assert!(current.movement(&Odds::new_fractional(1, 0)).is_err());
+        assert!(matches!(
+            Odds::new_decimal(f64::NAN).movement(&opening),
+            Err(OddsError::InfiniteOrNaN)
+        ));
+        assert!(current.movement(&Odds::new_decimal(f64::NAN)).is_err());
+        assert!(Odds::new_decimal(0.5).movement(&opening).is_err());
+        assert!(current.movement(&Odds::new_decimal(0.5)).is_err());
+    }
+
+    #[test]
+    fn test_from_str_as() {
+        assert_eq!(
+            Odds::from_str_as("-110", OddsFormatKind::American)
+                .unwrap()
+                .format(),
+            &OddsFormat::American(-110)
+        );
+        assert_eq!(
+            Odds::from_str_as("150", OddsFormatKind::American)
+                .unwrap()
+                .format(),
+            &OddsFormat::American(150)
+        );
+        assert_eq!(
+            Odds::from_str_as("150", OddsFormatKind::Decimal)
+                .unwrap()
+                .format(),
+            &OddsFormat::Decimal(150.0)
+        );
+        assert_eq!(
+            Odds::from_str_as("3/2", OddsFormatKind::Fractional)
+                .unwrap()
+                .format(),
+            &OddsFormat::Fractional(3, 2)
+        );
+
+        // Mismatched input names the expected format
+        match Odds::from_str_as("3/2", OddsFormatKind::Decimal) {
+            Err(OddsError::ParseError(error)) => assert!(error.to_string().contains("decimal")),
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+        match Odds::from_str_as("2.5", OddsFormatKind::American) {
+            Err(OddsError::ParseError(error)) => assert!(error.to_string().contains("American")),
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+        match Odds::from_str_as("2.5", OddsFormatKind::Fractional) {
+            Err(OddsError::ParseError(error)) => {
+                assert!(error.to_string().contains("fractional"))
+            }
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_complement() {
+        let even = Odds::new_american(100).complement().unwrap();
+        assert!(even.is_even());
+
+        let complement = Odds::new_decimal(1.25).complement().unwrap();
+        assert!((complement.to_decimal().unwrap() - 5.0).abs() < 1e-9);
+        assert!((complement.implied_probability().unwrap() - 0.2).abs() < 1e-9);
+
+        // Complementing twice returns the original price
+        let original = Odds::new_fractional(7, 2);
+        let round_trip = original.complement().unwrap().complement().unwrap();
+        assert!(round_trip.approx_eq_default(&original));
+
+        assert!(matches!(
+            Odds::new_fractional(0, 1).complement(),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(Odds::new_decimal(0.5).complement().is_err());
+    }
+
+    #[test]
+    fn test_format_newtypes() {
+        let american = AmericanOdds::new(150).unwrap();
+        let odds: Odds = american.into();
+        assert_eq!(odds.format(), &OddsFormat::American(150));
+        assert_eq!(AmericanOdds::try_from(odds).unwrap(), american);
+        assert_eq!(AmericanOdds::new(50).unwrap().value(), -200);
+        assert!(AmericanOdds::new(0).is_err());
+
+        let decimal = DecimalOdds::new(2.5).unwrap();
+        let odds: Odds = decimal.into();
+        assert_eq!(odds.format(), &OddsFormat::Decimal(2.5));
+        assert_eq!(DecimalOdds::try_from(odds).unwrap(), decimal);
+        assert!(DecimalOdds::new(f64::NAN).is_err());
+
+        let fractional = FractionalOdds::new(3, 2).unwrap();
+        let odds: Odds = fractional.into();
+        assert_eq!(odds.format(), &OddsFormat::Fractional(3, 2));
+        assert_eq!(FractionalOdds::try_from(odds).unwrap(), fractional);
+        assert!(FractionalOdds::new(1, 0).is_err());
+
+        // Conversions across formats go through the existing conversion logic
+        assert_eq!(
+            AmericanOdds::try_from(Odds::from(fractional))
+                .unwrap()
+                .value(),
+            150
+        );
+        assert_eq!(
+            DecimalOdds::try_from(Odds::from(american)).unwrap().value(),
+            2.5
+        );
+        assert!(DecimalOdds::try_from(Odds::new_fractional(1, 0)).is_err());
+    }
+
+    #[test]
+    fn test_is_steam_move() {
+        let previous = Odds::new_decimal(2.0);
+        assert!(Odds::new_decimal(1.5)
+            .is_steam_move(&previous, 0.1)
+            .unwrap());
+        // Drifting counts as well as shortening
+        assert!(previous
+            .is_steam_move(&Odds::new_decimal(1.5), 0.1)
+            .unwrap());
+        assert!(!Odds::new_decimal(1.9)
+            .is_steam_move(&previous, 0.1)
+            .unwrap());
+
+        assert!(matches!(
+            previous.is_steam_move(&previous, 0.0),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(previous.is_steam_move(&previous, 1.0).is_err());
+        assert!(matches!(
+            previous.is_steam_move(&previous, f64::NAN),
+            Err(OddsError::InfiniteOrNaN)
+        ));
+
+        // Invalid prices are errors rather than raising or suppressing an alert
+        for invalid in [Odds::new_decimal(f64::NAN), Odds::new_decimal(0.5)] {
+            assert!(invalid.is_steam_move(&previous, 0.1).is_err());
+            assert!(previous.is_steam_move(&invalid, 0.1).is_err());
+        }
+    }
+
+    #[test]
+    fn test_longshot_fractional() {
+        let longshot: Odds = "5000/1".parse().unwrap();
+        assert_eq!(longshot.to_decimal().unwrap(), 5001.0);
+
+        // Conversions of a valid longshot stay within the default limits
+        assert!(longshot.normalized().unwrap().validate().is_ok());
+        assert_eq!(DecimalOdds::try_from(longshot).unwrap().value(), 5001.0);
+        assert_eq!(AmericanOdds::try_from(longshot).unwrap().value(), 500_000);
+
+        let extreme = Odds::parse_fractional("100000/1").unwrap();
+        assert_eq!(extreme.to_decimal().unwrap(), 100_001.0);
+        assert_eq!(extreme.to_string(), "100000/1");
+
+        assert!(matches!(
+            "100001/1".parse::<Odds>(),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_debug_shows_decimal() {
+        let debug = format!("{:?}", Odds::new_fractional(3, 2));
+        assert_eq!(debug, "Odds { format: Fractional(3, 2), decimal: 2.5 }");
+        assert!(debug.contains("2.5"));
+
+        assert_eq!(
+            format!("{:?}", Odds::new_fractional(3, 0)),
+            "Odds { format: Fractional(3, 0), decimal: <invalid> }"
+        );
+        assert!(format!("{:#?}", Odds::new_american(150)).contains("decimal: 2.5"));
+    }
+
+    #[test]
+    fn test_simplified() {
+        assert_eq!(
+            Odds::new_fractional(6, 4).simplified().format(),
+            &OddsFormat::Fractional(3, 2)
+        );
+        assert_eq!(
+            Odds::new_fractional(100, 100).simplified().format(),
+            &OddsFormat::Fractional(1, 1)
+        );
+        assert_eq!(
+            Odds::new_fractional(7, 3).simplified().format(),
+            &OddsFormat::Fractional(7, 3)
+        );
+        assert_eq!(
+            Odds::new_fractional(0, 5).simplified().format(),
+            &OddsFormat::Fractional(0, 1)
+        );
+        assert_eq!(
+            Odds::new_fractional(0, 0).simplified().format(),
+            &OddsFormat::Fractional(0, 0)
+        );
+
+        // Other formats are untouched
+        assert_eq!(
+            Odds::new_american(-110).simplified().format(),
+            &OddsFormat::American(-110)
+        );
+        assert_eq!(
+            Odds::new_decimal(2.5).simplified().format(),
+            &OddsFormat::Decimal(2.5)
+        );
+    }
+
+    #[test]
+    fn test_to_decimal_rounded() {
+        let odds = Odds::new_decimal(1.909);
+        assert_eq!(odds.to_decimal_rounded(2).unwrap(), 1.91);
+        assert_eq!(odds.to_decimal_rounded(1).unwrap(), 1.9);
+        assert_eq!(odds.to_decimal_rounded(0).unwrap(), 2.0);
+
+        assert_eq!(
+            Odds::new_fractional(10, 11).to_decimal_rounded(3).unwrap(),
+            1.909
+        );
+        // Rounding never produces a price below 1.0
+        assert_eq!(Odds::new_decimal(1.004).to_decimal_rounded(2).unwrap(), 1.0);
+        assert_eq!(Odds::new_decimal(2.5).to_decimal_rounded(400).unwrap(), 2.5);
+        assert!(Odds::new_decimal(0.5).to_decimal_rounded(2).is_err());
+    }
+
+    #[test]
+    fn test_equivalent() {
+        let american = Odds::new_american(150);
+        let decimal = Odds::new_decimal(2.5);
+        let fractional = Odds::new_fractional(3, 2);
+
+        for (a, b) in [
+            (american, decimal),
+            (decimal, fractional),
+            (fractional, american),
+        ] {
+            assert!(a.equivalent(&b));
+            assert!(b.equivalent(&a));
+            assert_eq!(a, b);
+        }
+        assert!(!american.equivalent(&Odds::new_american(145)));
+
+        // `==` rounds to three places; `equivalent` does not
+        assert_eq!(Odds::new_decimal(1.909), Odds::new_fractional(10, 11));
+        assert!(!Odds::new_decimal(1.909).equivalent(&Odds::new_fractional(10, 11)));
+
+        let invalid = Odds::new_fractional(1, 0);
+        assert!(!invalid.equivalent(&invalid));
+    }
+
+    #[test]
+    fn test_american_grammar_is_strict() {
+        for input in ["+-150", "-+150", "--200", "++200", "007", "+0150", "-0110"] {
+            assert!(
+                matches!(input.parse::<Odds>(), Err(OddsError::ParseError(_))),
+                "input {:?} should be rejected",
+                input
+            );
+            assert!(
+                matches!(Odds::parse_american(input), Err(OddsError::ParseError(_))),
+                "input {:?} should be rejected",
+                input
+            );
+        }
+
+        // Well-formed values are unaffected
+        assert_eq!("+150".parse::<Odds>().unwrap(), Odds::new_american(150));
+        assert_eq!("100".parse::<Odds>().unwrap(), Odds::new_american(100));
+        assert_eq!(
+            Odds::parse_american("-110").unwrap(),
+            Odds::new_american(-110)
+        );
+        assert!(matches!(
+            "0".parse::<Odds>(),
+            Err(OddsError::InvalidAmericanOdds(_))
+        ));
+    }
+
+    #[test]
+    fn test_as_ratio_string() {
+        assert_eq!(Odds::new_decimal(2.5).as_ratio_string().unwrap(), "3 to 2");
+        assert_eq!(Odds::new_american(150).as_ratio_string().unwrap(), "3 to 2");
+        assert_eq!(
+            Odds::new_fractional(6, 4).as_ratio_string().unwrap(),
+            "3 to 2"
+        );
+        assert_eq!(Odds::new_decimal(2.0).as_ratio_string().unwrap(), "even");
+        assert_eq!(
+            Odds::new_fractional(1, 1).as_ratio_string().unwrap(),
+            "even"
+        );
+        assert_eq!(
+            Odds::new_american(-200).as_ratio_string().unwrap(),
+            "1 to 2"
+        );
+        assert!(Odds::new_fractional(1, 0).as_ratio_string().is_err());
+    }
+
+    #[test]
+    fn test_validate_with_severity() {
+        assert_eq!(
+            Odds::new_decimal(1.5).validate_with_severity().unwrap(),
+            ValidationLevel::Normal
+        );
+        assert_eq!(
+            Odds::new_american(-110).validate_with_severity().unwrap(),
+            ValidationLevel::Normal
+        );
+        assert_eq!(
+            Odds::new_decimal(900.0).validate_with_severity().unwrap(),
+            ValidationLevel::Extreme
+        );
+        // Outside the default limits is extreme rather than an error
+        assert_eq!(
+            Odds::new_decimal(1500.0).validate_with_severity().unwrap(),
+            ValidationLevel::Extreme
+        );
+        assert_eq!(
+            Odds::new_american(-50_000)
+                .validate_with_severity()
+                .unwrap(),
+            ValidationLevel::Extreme
+        );
+        assert_eq!(
+            Odds::new_fractional(150_000, 150_000)
+                .validate_with_severity()
+                .unwrap(),
+            ValidationLevel::Extreme
+        );
+
+        assert!(matches!(
+            Odds::new_decimal(0.5).validate_with_severity(),
+            Err(OddsError::InvalidDecimalOdds(_))
+        ));
+        assert!(matches!(
+            Odds::new_decimal(f64::NAN).validate_with_severity(),
+            Err(OddsError::InfiniteOrNaN)
+        ));
+        assert!(Odds::new_american(0).validate_with_severity().is_err());
+        assert_eq!(
+            Odds::new_fractional(1, 0).validate_with_severity(),
+            Err(OddsError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_conversion_or_default() {
+        assert_eq!(Odds::new_american(-200).to_decimal_or(f64::INFINITY), 1.5);
+        assert_eq!(Odds::new_fractional(3, 2).to_decimal_or(0.0), 2.5);
+        assert_eq!(Odds::new_decimal(2.5).to_american_or(0), 150);
+        assert_eq!(Odds::new_american(-110).to_american_or(0), -110);
+
+        let nan = Odds::new_decimal(f64::NAN);
+        assert_eq!(nan.to_decimal_or(f64::INFINITY), f64::INFINITY);
+        assert_eq!(nan.to_american_or(0), 0);
+        assert_eq!(Odds::new_fractional(1, 0).to_decimal_or(-1.0), -1.0);
+        assert_eq!(Odds::new_american(0).to_american_or(100), 100);
+    }
+
+    #[test]
+    fn test_apply_margin() {
+        let fair = Odds::new_decimal(2.0);
+        let quoted = fair.apply_margin(0.05).unwrap();
+        assert!((quoted.to_decimal().unwrap() - 2.0 / 1.05).abs() < 1e-12);
+        assert!(
+            (quoted.implied_probability().unwrap() - fair.implied_probability().unwrap() * 1.05)
+                .abs()
+                < 1e-12
+        );
+
+        // Removing the same margin recovers the fair price
+        let restored = quoted.remove_vig(0.05).unwrap();
+        assert!((restored.to_decimal().unwrap() - 2.0).abs() < 1e-12);
+        assert_eq!(fair.apply_margin(0.0).unwrap().to_decimal().unwrap(), 2.0);
+
+        assert!(matches!(
+            fair.apply_margin(-0.05),
+            Err(OddsError::NegativeValue(_))
+        ));
+        assert!(matches!(
+            fair.apply_margin(f64::NAN),
+            Err(OddsError::InfiniteOrNaN)
+        ));
+        // A 90% favorite cannot take a 20% margin
+        assert!(Odds::new_decimal(1.1).apply_margin(0.2).is_err());
+    }
+
+    #[test]
+    fn test_common_lines() {
+        assert!((probability_for_standard_line(-110).unwrap() - 0.5238).abs() < 1e-4);
+        assert!((probability_for_standard_line(200).unwrap() - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(probability_for_standard_line(-100), Some(0.5));
+        assert_eq!(probability_for_standard_line(100), Some(0.5));
+        assert_eq!(probability_for_standard_line(-1000), Some(1.0 / 1.1));
+        assert_eq!(probability_for_standard_line(-113), None);
+        assert_eq!(probability_for_standard_line(1050), None);
+        assert_eq!(probability_for_standard_line(0), None);
+
+        // The table is sorted and agrees exactly with implied_probability
+        let lines = common_lines();
+        assert!(lines.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for &(american, probability) in lines {
+            assert_eq!(
+                Odds::new_american(american).implied_probability().unwrap(),
+                probability,
+                "line {}",
+                american
+            );
+        }
+    }
+
+    #[test]
+    fn test_starting_price() {
+        for input in ["SP", "sp", " Sp "] {
+            let odds: Odds = input.parse().unwrap();
+            assert_eq!(odds.format(), &OddsFormat::StartingPrice);
+            assert!(odds.is_starting_price());
+        }
+
+        let sp = Odds::starting_price();
+        assert_eq!(sp.to_string(), "SP");
+        assert_eq!(format!("{:#}", sp), "SP");
+        assert_eq!(sp.to_decimal(), Err(OddsError::StartingPriceUndetermined));
+        assert_eq!(sp.to_american(), Err(OddsError::StartingPriceUndetermined));
+        assert_eq!(
+            sp.to_fractional(),
+            Err(OddsError::StartingPriceUndetermined)
+        );
+        assert_eq!(
+            sp.implied_probability(),
+            Err(OddsError::StartingPriceUndetermined)
+        );
+        assert_eq!(sp.validate(), Err(OddsError::StartingPriceUndetermined));
+        assert_eq!(
+            sp.to_decimal().unwrap_err().to_string(),
+            "Starting price not yet determined"
+        );
+
+        // Placeholders compare equal to each other but not to any priced odds
+        assert_eq!(sp, Odds::starting_price());
+        assert_ne!(sp, Odds::new_fractional(0, 1));
+        assert_eq!(sp.clamp_to_valid(), sp);
+        assert_eq!(Odds::from_key_value(&sp.to_key_value()).unwrap(), sp);
+    }
+
+    #[test]
+    fn test_correlated_parlay() {
+        let legs = [
+            Odds::new_american(-110),
+            Odds::new_decimal(2.5),
+            Odds::new_fractional(6, 4),
+        ];
+
+        // Zero correlation is the plain product of the leg prices
+        let plain: f64 = legs.iter().map(|leg| leg.to_decimal().unwrap()).product();
+        let parlay = Odds::correlated_parlay(&legs, 0.0).unwrap();
+        assert!((parlay.to_decimal().unwrap() - plain).abs() < 1e-9);
+
+        // Positive correlation shortens the price, down to the longest leg at 1.0
+        let correlated = Odds::correlated_parlay(&legs, 0.5).unwrap();
+        assert!(correlated.to_decimal().unwrap() < plain);
+        let locked = Odds::correlated_parlay(&legs, 1.0).unwrap();
+        assert!((locked.to_decimal().unwrap() - 2.5).abs() < 1e-9);
+
+        // Negative correlation lengthens it
+        let two_legs = [Odds::new_decimal(1.25), Odds::new_decimal(1.25)];
+        let anti = Odds::correlated_parlay(&two_legs, -1.0).unwrap();
+        assert!((anti.to_decimal().unwrap() - 5.0 / 3.0).abs() < 1e-9);
+        assert!(Odds::correlated_parlay(&legs, -1.0).is_err());
+
+        assert!(Odds::correlated_parlay(&[], 0.0).is_err());
+        assert!(Odds::correlated_parlay(&legs, 1.5).is_err());
+        assert!(Odds::correlated_parlay(&legs, -1.01).is_err());
+        assert!(Odds::correlated_parlay(&legs, f64::NAN).is_err());
+        assert!(Odds::correlated_parlay(&[Odds::new_american(0)], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_detect_format() {
+        use crate::OddsFormatKind::{American, Decimal, Fractional};
+
+        assert_eq!(Odds::detect_format("100"), Some(American));
+        assert_eq!(Odds::detect_format("+150"), Some(American));
+        assert_eq!(Odds::detect_format("-110"), Some(American));
+        assert_eq!(Odds::detect_format("PK"), Some(American));
+        assert_eq!(Odds::detect_format("2.5"), Some(Decimal));
+        assert_eq!(Odds::detect_format("2,5"), Some(Decimal));
+        assert_eq!(Odds::detect_format("40%"), Some(Decimal));
+        assert_eq!(Odds::detect_format("99999999999"), Some(Decimal));
+        assert_eq!(Odds::detect_format("3/2"), Some(Fractional));
+        assert_eq!(Odds::detect_format(" 10 / 11 "), Some(Fractional));
+        assert_eq!(Odds::detect_format("evens"), Some(Fractional));
+
+        // Detection does not validate: these are detected but fail to parse
+        assert_eq!(Odds::detect_format("0"), Some(American));
+        assert_eq!(Odds::detect_format("3/0"), Some(Fractional));
+        assert_eq!(Odds::detect_format("0.5"), Some(Decimal));
+
+        for input in [
+            "xyz", "", "SP", "+-150", "007", "3/2/1", "3/x", "1,000", "%",
+        ] {
+            assert_eq!(Odds::detect_format(input), None, "input {:?}", input);
+        }
+
+        // Detection agrees with the format FromStr parses into
+        for input in ["+150", "100", "pick", "2,5", "40%", "5/2", "EV", "1.91"] {
+            let kind = match input.parse::<Odds>().unwrap().format() {
+                OddsFormat::American(_) => American,
+                OddsFormat::Decimal(_) => Decimal,
+                OddsFormat::Fractional(_, _) => Fractional,
+                OddsFormat::StartingPrice => unreachable!(),
+            };
+            assert_eq!(Odds::detect_format(input), Some(kind), "input {:?}", input);
+        }
+        assert!("50%%".parse::<Odds>().is_err());
+        assert_eq!(Odds::detect_format("50%%"), None);
+    }
+
+    #[test]
+    fn test_fractional_description() {
+        let describe = |odds: Odds| {
+            let description = odds.fractional_description().unwrap();
+            (
+                description.numerator,
+                description.denominator,
+                description.is_odds_on,
+            )
+        };
+
+        assert_eq!(describe(Odds::new_fractional(1, 2)), (1, 2, true));
+        assert_eq!(describe(Odds::new_fractional(2, 1)), (2, 1, false));
+        assert_eq!(describe(Odds::new_fractional(4, 2)), (2, 1, false));
+        assert_eq!(describe(Odds::new_fractional(1, 1)), (1, 1, false));
+        assert_eq!(describe(Odds::new_american(-110)), (10, 11, true));
+        assert_eq!(describe(Odds::new_american(150)), (3, 2, false));
+        assert_eq!(describe(Odds::new_decimal(1.5)), (1, 2, true));
+
+        assert!(Odds::new_fractional(1, 0).fractional_description().is_err());
+        assert!(Odds::starting_price().fractional_description().is_err());
+    }
+
+    #[test]
+    fn test_implied_probability_bps() {
+        assert_eq!(
+            Odds::new_decimal(2.0).implied_probability_bps().unwrap(),
+            5000
+        );
+        assert_eq!(
+            Odds::new_american(-110).implied_probability_bps().unwrap(),
+            5238
+        );
+        assert_eq!(
+            Odds::new_american(100).implied_probability_bps().unwrap(),
+            5000
+        );
+        assert_eq!(
+            Odds::new_fractional(3, 1)
+                .implied_probability_bps()
+                .unwrap(),
+            2500
+        );
+        assert_eq!(
+            Odds::new_decimal(1.0).implied_probability_bps().unwrap(),
+            10_000
+        );
+        assert_eq!(
+            Odds::new_decimal(1000.0).implied_probability_bps().unwrap(),
+            10
+        );
+        // -200 is 66.666...%, which rounds up
+        assert_eq!(
+            Odds::new_american(-200).implied_probability_bps().unwrap(),
+            6667
+        );
+
+        assert!(Odds::new_decimal(0.5).implied_probability_bps().is_err());
+        assert!(Odds::new_fractional(1, 0)
+            .implied_probability_bps()
+            .is_err());
+    }
+
+    #[test]
+    fn test_to_fractional_to_one() {
+        assert_eq!(
+            Odds::new_decimal(6.0).to_fractional_to_one().unwrap(),
+            (5.0, 1)
+        );
+        assert_eq!(
+            Odds::new_fractional(5, 2).to_fractional_to_one().unwrap(),
+            (2.5, 1)
+        );
+        assert_eq!(
+            Odds::new_american(300).to_fractional_to_one().unwrap(),
+            (3.0, 1)
+        );
+        assert_eq!(
+            Odds::new_fractional(1, 1).to_fractional_to_one().unwrap(),
+            (1.0, 1)
+        );
+
+        // Odds-on prices pay a fraction of the stake
+        assert_eq!(
+            Odds::new_fractional(1, 4).to_fractional_to_one().unwrap(),
+            (0.25, 1)
+        );
+        let (profit, one) = Odds::new_american(-110).to_fractional_to_one().unwrap();
+        assert!((profit - 10.0 / 11.0).abs() < 1e-12);
+        assert_eq!(one, 1);
+
+        assert!(Odds::new_fractional(1, 0).to_fractional_to_one().is_err());
+        assert!(Odds::new_decimal(0.5).to_fractional_to_one().is_err());
+    }
+
     #[test]
     fn test_validation() {
         let invalid_american = Odds::new_american(0);
@@ -123,6 +1649,25 @@ mod tests {
         assert_eq!(format!("{}", fractional), "3/2");
     }
 
+    #[test]
+    fn test_display_alternate_labels() {
+        let american_pos = Odds::new_american(150);
+        assert_eq!(format!("{:#}", american_pos), "American(+150)");
+        assert_eq!(format!("{}", american_pos), "+150");
+
+        let american_neg = Odds::new_american(-200);
+        assert_eq!(format!("{:#}", american_neg), "American(-200)");
+        assert_eq!(format!("{}", american_neg), "-200");
+
+        let decimal = Odds::new_decimal(2.5);
+        assert_eq!(format!("{:#}", decimal), "Decimal(2.50)");
+        assert_eq!(format!("{}", decimal), "2.50");
+
+        let fractional = Odds::new_fractional(3, 2);
+        assert_eq!(format!("{:#}", fractional), "Fractional(3/2)");
+        assert_eq!(format!("{}", fractional), "3/2");
+    }
+
     #[test]
     fn test_string_parsing() {
         let american_pos: Odds = "+150".parse().unwrap();
@@ -141,6 +1686,151 @@ mod tests {
         assert!(invalid.is_err());
     }
 
+    #[test]
+    fn test_even_money_keyword_parsing() {
+        for input in ["evens", "Evens", "EVS", " EVS ", "even", "EVEN", "ev", "EV"] {
+            let odds: Odds = input.parse().unwrap();
+            assert_eq!(
+                odds.format(),
+                &OddsFormat::Fractional(1, 1),
+                "input {:?}",
+                input
+            );
+        }
+
+        assert!("evenly".parse::<Odds>().is_err());
+        assert!("e v".parse::<Odds>().is_err());
+    }
+
+    #[test]
+    fn test_pick_em() {
+        for input in ["PK", "pk", "Pick", " PICK "] {
+            let odds: Odds = input.parse().unwrap();
+            assert_eq!(
+                odds.format(),
+                &OddsFormat::American(100),
+                "input {:?}",
+                input
+            );
+        }
+
+        assert_eq!(format!("{:#}", Odds::new_american(100)), "PK");
+        assert_eq!(format!("{:#}", Odds::new_american_raw(-100)), "PK");
+        assert_eq!(format!("{}", Odds::new_american(100)), "+100");
+        // Only American even money is shown as PK
+        assert_eq!(format!("{:#}", Odds::new_decimal(2.0)), "Decimal(2.00)");
+        assert_eq!(format!("{:#}", Odds::new_american(105)), "American(+105)");
+    }
+
+    #[test]
+    fn test_decimal_comma_parsing() {
+        let odds: Odds = "1,91".parse().unwrap();
+        assert_eq!(odds.format(), &OddsFormat::Decimal(1.91));
+
+        let odds: Odds = " 2,50 ".parse().unwrap();
+        assert_eq!(odds.format(), &OddsFormat::Decimal(2.5));
+
+        for input in ["1,000", "2,5,0", "1.000,5", "1,5.0", ","] {
+            assert!(
+                matches!(input.parse::<Odds>(), Err(OddsError::ParseError(_))),
+                "input {:?} should be rejected",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_percentage_parsing() {
+        let odds: Odds = "50%".parse().unwrap();
+        assert_eq!(odds.format(), &OddsFormat::Decimal(2.0));
+
+        let odds: Odds = "100%".parse().unwrap();
+        assert_eq!(odds.format(), &OddsFormat::Decimal(1.0));
+
+        let odds: Odds = " 52.4 % ".parse().unwrap();
+        assert!((odds.implied_probability().unwrap() - 0.524).abs() < 1e-12);
+
+        assert!(matches!(
+            "0%".parse::<Odds>(),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            "100.1%".parse::<Odds>(),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            "-5%".parse::<Odds>(),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(matches!(
+            "abc%".parse::<Odds>(),
+            Err(OddsError::ParseError(_))
+        ));
+        assert!(matches!("%".parse::<Odds>(), Err(OddsError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_whitespace_tolerant_fractional_parsing() {
+        let odds: Odds = "3 / 2".parse().unwrap();
+        assert_eq!(odds.format(), &OddsFormat::Fractional(3, 2));
+
+        let odds: Odds = " 10/ 11 ".parse().unwrap();
+        assert_eq!(odds.format(), &OddsFormat::Fractional(10, 11));
+
+        let odds: Odds = "5\t/\t2".parse().unwrap();
+        assert_eq!(odds.format(), &OddsFormat::Fractional(5, 2));
+
+        for input in ["3 2/1", "3/1 0", "+3/2", "3/-2"] {
+            assert!(
+                matches!(input.parse::<Odds>(), Err(OddsError::ParseError(_))),
+                "input {:?} should be rejected",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_explicit_parsing() {
+        assert_eq!(
+            Odds::parse_decimal("100").unwrap().format(),
+            &OddsFormat::Decimal(100.0)
+        );
+        assert_eq!(
+            Odds::parse_american("100").unwrap().format(),
+            &OddsFormat::American(100)
+        );
+        assert_eq!(
+            Odds::parse_american(" -150 ").unwrap().format(),
+            &OddsFormat::American(-150)
+        );
+        assert_eq!(
+            Odds::parse_decimal("1,91").unwrap().format(),
+            &OddsFormat::Decimal(1.91)
+        );
+        assert_eq!(
+            Odds::parse_fractional("3/2").unwrap().format(),
+            &OddsFormat::Fractional(3, 2)
+        );
+
+        assert!(matches!(
+            Odds::parse_american("3/2"),
+            Err(OddsError::ParseError(_))
+        ));
+        assert!(matches!(
+            Odds::parse_decimal("+150x"),
+            Err(OddsError::ParseError(_))
+        ));
+        assert!(matches!(
+            Odds::parse_fractional("150"),
+            Err(OddsError::ParseError(_))
+        ));
+        assert!(Odds::parse_american("0").is_err());
+        assert_eq!(
+            Odds::parse_fractional("3/0"),
+            Err(OddsError::ZeroDenominator)
+        );
+    }
+
     #[test]
     fn test_round_trip_conversions() {
         // American -> Decimal -> American
@@ -163,7 +1853,7 @@ mod tests {
         assert!(minus_100_american.validate().is_ok());
 
         // Test very large values
-        let large_american = Odds::new_american(150000);
+        let large_american = Odds::new_american(15_000_000);
         assert!(large_american.validate().is_err());
 
         // Test decimal odds exactly 1.0 (should be valid)
@@ -171,7 +1861,7 @@ mod tests {
         assert!(decimal_one.validate().is_ok());
 
         // Test very large decimal odds
-        let large_decimal = Odds::new_decimal(1500.0);
+        let large_decimal = Odds::new_decimal(150_000.0);
         assert!(large_decimal.validate().is_err());
 
         // Test infinite and NaN values
@@ -182,7 +1872,7 @@ mod tests {
         assert_eq!(nan_decimal.validate(), Err(OddsError::InfiniteOrNaN));
 
         // Test large fractional odds
-        let large_fractional = Odds::new_fractional(15000, 1);
+        let large_fractional = Odds::new_fractional(150_000, 1);
         assert!(large_fractional.validate().is_err());
     }
 
@@ -219,6 +1909,70 @@ mod tests {
         assert!(matches!(zero_den, Err(OddsError::ZeroDenominator)));
     }
 
+    #[test]
+    fn test_parse_error_source_chain() {
+        use std::error::Error;
+
+        let overflow = "+99999999999".parse::<Odds>().unwrap_err();
+        assert!(matches!(overflow, OddsError::ParseError(_)));
+        let source = overflow
+            .source()
+            .expect("American overflow should keep its source");
+        assert!(source.is::<std::num::ParseIntError>());
+        // Display output is unchanged
+        assert_eq!(
+            overflow.to_string(),
+            "Failed to parse odds string: Invalid American odds format: '+99999999999'"
+        );
+
+        let american = Odds::parse_american("+1.5").unwrap_err();
+        assert!(american.source().is_some());
+        let decimal = Odds::parse_decimal("abc").unwrap_err();
+        assert!(decimal.source().unwrap().is::<std::num::ParseFloatError>());
+
+        // Structural failures have no underlying cause
+        let structural = "3/2/1".parse::<Odds>().unwrap_err();
+        assert!(structural.source().is_none());
+        assert!(OddsError::ZeroDenominator.source().is_none());
+    }
+
+    #[test]
+    fn test_parse_error_offset() {
+        let offset = |result: Result<Odds, OddsError>| match result.unwrap_err() {
+            OddsError::ParseError(error) => error.offset(),
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+
+        assert_eq!(offset("3/a2".parse()), Some(2));
+        assert_eq!(offset("3a/2".parse()), Some(1));
+        assert_eq!(offset("  3/a2".parse()), Some(4));
+        assert_eq!(offset("3 / 2x".parse()), Some(5));
+        assert_eq!(offset("3/2/1".parse()), Some(3));
+        assert_eq!(offset("/2".parse()), Some(0));
+        assert_eq!(offset("+15a0".parse()), Some(3));
+        assert_eq!(offset("+-150".parse()), Some(1));
+        assert_eq!(offset(" 007".parse()), Some(1));
+        assert_eq!(offset("+".parse()), Some(1));
+        assert_eq!(offset("1,000".parse()), Some(1));
+        assert_eq!(offset(Odds::parse_american(" 1x0")), Some(2));
+        assert_eq!(offset(Odds::parse_decimal(" 1.5,0")), Some(4));
+        assert_eq!(offset(Odds::parse_fractional(" 3/2/1")), Some(4));
+        assert_eq!(offset(Odds::parse_american_lenient("(1x0)")), Some(2));
+        assert_eq!(offset(Odds::parse_american_lenient(" 1x0u")), Some(2));
+        assert_eq!(offset(Odds::parse_american_lenient("150 EUR")), Some(4));
+
+        // Failures that are not tied to one position carry no offset
+        assert_eq!(offset("+99999999999".parse()), None);
+        assert_eq!(offset("xyz".parse()), None);
+        assert_eq!(offset("".parse()), None);
+
+        // Display output is unchanged
+        assert_eq!(
+            "3/a2".parse::<Odds>().unwrap_err().to_string(),
+            "Failed to parse odds string: Invalid denominator: 'a2'"
+        );
+    }
+
     #[test]
     fn test_conversion_edge_cases() {
         // Test conversion of very small decimal odds
@@ -364,6 +2118,54 @@ mod tests {
                     decimal_prob, fractional_prob);
             }
         }
+
+        #[test]
+        fn prop_short_decimal_never_enters_american_gap(decimal in 1.000_001f64..2.0f64) {
+            let odds = Odds::new_decimal(decimal);
+            for mode in [
+                RoundingMode::HalfUp,
+                RoundingMode::HalfEven,
+                RoundingMode::Floor,
+                RoundingMode::Ceil,
+            ] {
+                let american = odds.to_american_with_rounding(mode).unwrap();
+                prop_assert!(american <= -100,
+                    "Decimal {} -> American {} under {:?}", decimal, american, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_validator() {
+        let longshot = Odds::new_decimal(500_000.0);
+        assert!(matches!(
+            longshot.validate(),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+        assert!(Validator::new().validate(&longshot).is_err());
+        assert!(Validator::default().validate(&longshot).is_err());
+
+        let loose = Validator::new()
+            .max_decimal(1_000_000.0)
+            .max_american_magnitude(100_000_000)
+            .max_fractional_part(1_000_000);
+        assert!(loose.validate(&longshot).is_ok());
+        assert!(loose.validate(&Odds::new_american(49_999_900)).is_ok());
+        assert!(loose.validate(&Odds::new_fractional(499_999, 10)).is_ok());
+
+        let strict = Validator::new().max_decimal(50.0).max_fractional_part(100);
+        assert!(strict.validate(&Odds::new_decimal(75.0)).is_err());
+        assert!(strict.validate(&Odds::new_fractional(150, 1)).is_err());
+        assert!(strict.validate(&Odds::new_american(-110)).is_ok());
+
+        // Structural checks apply regardless of the limits
+        assert!(loose.validate(&Odds::new_american(0)).is_err());
+        assert!(loose.validate(&Odds::new_decimal(0.5)).is_err());
+        assert!(loose.validate(&Odds::new_decimal(f64::NAN)).is_err());
+        assert_eq!(
+            loose.validate(&Odds::new_fractional(1, 0)),
+            Err(OddsError::ZeroDenominator)
+        );
     }
 
     #[test]
@@ -373,31 +2175,37 @@ mod tests {
         assert!(min_decimal.validate().is_ok());
         assert_eq!(min_decimal.implied_probability().unwrap(), 1.0);
 
-        let large_decimal = Odds::new_decimal(999.99);
+        let large_decimal = Odds::new_decimal(100_000.99);
         assert!(large_decimal.validate().is_ok());
 
-        let max_decimal = Odds::new_decimal(1000.0);
+        let max_decimal = Odds::new_decimal(100_001.0);
         assert!(max_decimal.validate().is_ok());
 
-        let too_large_decimal = Odds::new_decimal(1000.01);
+        let too_large_decimal = Odds::new_decimal(100_001.01);
         assert!(too_large_decimal.validate().is_err());
 
         // Test American odds boundaries
-        let max_american = Odds::new_american(99999);
+        let max_american = Odds::new_american(10_000_000);
         assert!(max_american.validate().is_ok());
 
-        let min_american = Odds::new_american(-99999);
+        let min_american = Odds::new_american(-10_000_000);
         assert!(min_american.validate().is_ok());
 
-        let too_large_american = Odds::new_american(100001);
+        let too_large_american = Odds::new_american(10_000_001);
         assert!(too_large_american.validate().is_err());
 
         // Test fractional boundaries
-        let max_fractional = Odds::new_fractional(9999, 1);
+        let max_fractional = Odds::new_fractional(100_000, 1);
         assert!(max_fractional.validate().is_ok());
 
-        let too_large_fractional = Odds::new_fractional(10001, 1);
+        let too_large_fractional = Odds::new_fractional(100_001, 1);
         assert!(too_large_fractional.validate().is_err());
+
+        // The largest fraction converts to prices the other limits still accept
+        let formats = max_fractional.to_all_formats().unwrap();
+        assert!(Odds::new_decimal(formats.decimal).validate().is_ok());
+        assert!(Odds::new_american(formats.american).validate().is_ok());
+        assert!(max_fractional.normalized().unwrap().validate().is_ok());
     }
 
     #[test]
@@ -0,0 +1,313 @@
+//! Betting markets made up of several mutually exclusive outcomes.
+//!
+//! This module provides the [`Market`] type, which measures the bookmaker's margin
+//! (overround) across a full set of outcomes and removes it to recover fair prices.
+//! Markets can be built from bare odds with [`Market::from_odds`], or outcome by
+//! outcome with names using [`Market::new`] and [`Market::add`].
+
+use crate::conversions::{implied_probabilities, normalized_probabilities};
+use crate::{Odds, OddsError};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A set of mutually exclusive outcomes priced by a single bookmaker.
+///
+/// Exactly one outcome is expected to win, so the fair probabilities of all
+/// outcomes sum to 1. Any excess in the implied probabilities is the bookmaker's
+/// margin, known as the overround or vig.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Market, Odds};
+///
+/// let market = Market::from_odds(vec![Odds::new_american(-110), Odds::new_american(-110)]);
+/// assert!((market.overround().unwrap() - 0.0476).abs() < 0.0001);
+///
+/// let fair = market.fair_odds().unwrap();
+/// assert!((fair[0].to_decimal().unwrap() - 2.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Market {
+    description: String,
+    outcomes: Vec<MarketOutcome>,
+}
+
+/// A single named outcome within a [`Market`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketOutcome {
+    name: String,
+    odds: Odds,
+    is_favorite: bool,
+}
+
+impl MarketOutcome {
+    /// Creates an outcome, marking it as a favorite when its implied probability
+    /// is above 50%.
+    fn new(name: String, odds: Odds) -> Self {
+        Self {
+            name,
+            odds,
+            is_favorite: odds.is_favorite(),
+        }
+    }
+
+    /// Returns the name of the outcome.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the odds of the outcome.
+    pub fn odds(&self) -> Odds {
+        self.odds
+    }
+
+    /// Returns true when the outcome's implied probability is above 50%.
+    pub fn is_favorite(&self) -> bool {
+        self.is_favorite
+    }
+}
+
+impl Market {
+    /// Creates an empty market with a description.
+    ///
+    /// Outcomes are added one at a time with [`Market::add`].
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - A human-readable description of the market
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Market, Odds};
+    ///
+    /// let market = Market::new("Manchester City vs Liverpool")
+    ///     .add("Manchester City", Odds::new_decimal(2.10))?
+    ///     .add("Draw", Odds::new_decimal(3.40))?
+    ///     .add("Liverpool", Odds::new_decimal(3.75))?;
+    ///
+    /// assert_eq!(market.description(), "Manchester City vs Liverpool");
+    /// assert_eq!(market.favorite().unwrap().name(), "Manchester City");
+    /// # Ok::<(), odds_converter::OddsError>(())
+    /// ```
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            outcomes: Vec::new(),
+        }
+    }
+
+    /// Adds a named outcome to the market.
+    ///
+    /// The odds are validated before being added, and the outcome is marked as a
+    /// favorite when its implied probability is above 50%.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the outcome
+    /// * `odds` - The odds of the outcome
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Market)` with the outcome appended, or an `Err(OddsError)` if the
+    /// odds are invalid.
+    pub fn add(mut self, name: impl Into<String>, odds: Odds) -> Result<Self, OddsError> {
+        odds.validate()?;
+        self.outcomes.push(MarketOutcome::new(name.into(), odds));
+        Ok(self)
+    }
+
+    /// Creates a market from the odds of each outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `outcomes` - The odds of each mutually exclusive outcome
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Market, Odds};
+    ///
+    /// let market = Market::from_odds(vec![Odds::new_decimal(1.9), Odds::new_decimal(1.9)]);
+    /// assert_eq!(market.odds().len(), 2);
+    /// ```
+    pub fn from_odds(outcomes: Vec<Odds>) -> Self {
+        Self {
+            description: String::new(),
+            outcomes: outcomes
+                .into_iter()
+                .map(|odds| MarketOutcome::new(String::new(), odds))
+                .collect(),
+        }
+    }
+
+    /// Returns the description of the market.
+    ///
+    /// Markets created with [`Market::from_odds`] have an empty description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the odds of each outcome in the market, in the order they were added.
+    pub fn odds(&self) -> Vec<Odds> {
+        self.outcomes.iter().map(MarketOutcome::odds).collect()
+    }
+
+    /// Returns every outcome in the market, in the order they were added.
+    ///
+    /// Outcomes of markets created with [`Market::from_odds`] have empty names.
+    pub fn outcomes(&self) -> &[MarketOutcome] {
+        &self.outcomes
+    }
+
+    /// Returns the outcome with the shortest price.
+    ///
+    /// Outcomes whose odds cannot be converted are ignored. Returns `None` when the
+    /// market has no convertible outcomes.
+    pub fn favorite(&self) -> Option<&MarketOutcome> {
+        self.outcomes
+            .iter()
+            .filter_map(|outcome| Some((outcome, outcome.odds.to_decimal().ok()?)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(outcome, _)| outcome)
+    }
+
+    /// Calculates the market's overround.
+    ///
+    /// The overround is the sum of the outcomes' implied probabilities minus 1. A
+    /// fair market has an overround of 0.0, while a typical -110/-110 line has an
+    /// overround of about 0.0476 (4.76%).
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the overround as a fraction, or an
+    /// `Err(OddsError)` if the market is empty or contains invalid odds.
+    pub fn overround(&self) -> Result<f64, OddsError> {
+        Ok(self.total_probability()? - 1.0)
+    }
+
+    /// Calculates the bookmaker's hold as a percentage.
+    ///
+    /// The hold is the share of all money wagered that the bookmaker expects to
+    /// keep when action is balanced to the prices: `(total - 1) / total * 100`,
+    /// where `total` is the sum of implied probabilities. It is always smaller than
+    /// the overround; a -110/-110 line has an overround of 4.76% but a hold of
+    /// about 4.55%.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the hold in percent, or an `Err(OddsError)` if
+    /// the market is empty or contains invalid odds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Market, Odds};
+    ///
+    /// let market = Market::from_odds(vec![Odds::new_american(-110), Odds::new_american(-110)]);
+    /// assert!((market.hold_percentage().unwrap() - 4.545).abs() < 0.001);
+    /// ```
+    pub fn hold_percentage(&self) -> Result<f64, OddsError> {
+        let total = self.total_probability()?;
+        Ok((total - 1.0) / total * 100.0)
+    }
+
+    /// Calculates the fair (vig-free) probability of each outcome.
+    ///
+    /// Each outcome's implied probability is divided by the market total, so the
+    /// returned probabilities sum to 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vec<f64>)` with one probability per outcome, or an
+    /// `Err(OddsError)` if the market is empty or contains invalid odds.
+    pub fn fair_probabilities(&self) -> Result<Vec<f64>, OddsError> {
+        normalized_probabilities(&self.odds())
+    }
+
+    /// Calculates fair (vig-free) decimal odds for each outcome.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vec<Odds>)` with decimal odds for each outcome, or an
+    /// `Err(OddsError)` if the market is empty or contains invalid odds.
+    pub fn fair_odds(&self) -> Result<Vec<Odds>, OddsError> {
+        Ok(self
+            .fair_probabilities()?
+            .into_iter()
+            .map(|probability| Odds::new_decimal(1.0 / probability))
+            .collect())
+    }
+
+    /// Calculates fair (vig-free) American odds for each outcome.
+    ///
+    /// The market is devigged as in [`Market::fair_odds`] and each fair price is
+    /// converted to whole American odds. Values that would fall between -99 and +99
+    /// are normalized as in [`Odds::new_american`], so every line is a legal price.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vec<i32>)` with American odds for each outcome, or an
+    /// `Err(OddsError)` if the market is empty, contains invalid odds, or has an
+    /// outcome with a fair probability of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Market, Odds};
+    ///
+    /// let market = Market::from_odds(vec![Odds::new_american(-150), Odds::new_american(130)]);
+    /// assert_eq!(market.fair_american().unwrap(), vec![-138, 138]);
+    /// ```
+    pub fn fair_american(&self) -> Result<Vec<i32>, OddsError> {
+        self.fair_odds()?
+            .iter()
+            .map(|odds| odds.to_american())
+            .collect()
+    }
+
+    /// Calculates the fair (no-vig) line for both sides of a two-way market.
+    ///
+    /// This is [`Market::fair_odds`] for markets with exactly two outcomes, such as
+    /// a moneyline or a point spread, returned as a pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok((Odds, Odds))` with fair decimal odds for each side, in market
+    /// order, or an `Err(OddsError)` if the market does not have exactly two
+    /// outcomes or contains invalid odds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Market, Odds};
+    ///
+    /// let market = Market::from_odds(vec![Odds::new_american(-110), Odds::new_american(-110)]);
+    /// let (home, away) = market.two_way_fair().unwrap();
+    /// assert!((home.to_decimal().unwrap() - 2.0).abs() < 1e-9);
+    /// assert!((away.to_decimal().unwrap() - 2.0).abs() < 1e-9);
+    /// ```
+    pub fn two_way_fair(&self) -> Result<(Odds, Odds), OddsError> {
+        if self.outcomes.len() != 2 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "A two-way market must have exactly two outcomes, got {}",
+                self.outcomes.len()
+            )));
+        }
+
+        let fair = self.fair_odds()?;
+        Ok((fair[0], fair[1]))
+    }
+
+    /// Sums the implied probabilities of every outcome.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(f64)` containing the total implied probability, or an
+    /// `Err(OddsError)` if the market is empty or contains invalid odds.
+    pub fn total_probability(&self) -> Result<f64, OddsError> {
+        Ok(implied_probabilities(&self.odds())?.iter().sum())
+    }
+}
@@ -0,0 +1,77 @@
+//! Floating-point functions that work with or without the standard library.
+//!
+//! The inherent `f64` methods such as `round` and `ln` live in `std`, so without
+//! the `std` feature these forward to `libm` instead. With `std` enabled the
+//! standard library implementations are used and results are unchanged.
+
+#[cfg(feature = "std")]
+mod imp {
+    pub(crate) fn abs(x: f64) -> f64 {
+        x.abs()
+    }
+
+    pub(crate) fn ceil(x: f64) -> f64 {
+        x.ceil()
+    }
+
+    pub(crate) fn exp(x: f64) -> f64 {
+        x.exp()
+    }
+
+    pub(crate) fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+
+    pub(crate) fn ln(x: f64) -> f64 {
+        x.ln()
+    }
+
+    pub(crate) fn powf(x: f64, y: f64) -> f64 {
+        x.powf(y)
+    }
+
+    pub(crate) fn round(x: f64) -> f64 {
+        x.round()
+    }
+
+    pub(crate) fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    pub(crate) fn abs(x: f64) -> f64 {
+        libm::fabs(x)
+    }
+
+    pub(crate) fn ceil(x: f64) -> f64 {
+        libm::ceil(x)
+    }
+
+    pub(crate) fn exp(x: f64) -> f64 {
+        libm::exp(x)
+    }
+
+    pub(crate) fn floor(x: f64) -> f64 {
+        libm::floor(x)
+    }
+
+    pub(crate) fn ln(x: f64) -> f64 {
+        libm::log(x)
+    }
+
+    pub(crate) fn powf(x: f64, y: f64) -> f64 {
+        libm::pow(x, y)
+    }
+
+    pub(crate) fn round(x: f64) -> f64 {
+        libm::round(x)
+    }
+
+    pub(crate) fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+}
+
+pub(crate) use imp::*;
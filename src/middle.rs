@@ -0,0 +1,99 @@
+//! Middle opportunities on totals and spreads.
+//!
+//! A middle combines two bets on opposite sides of different lines, such as Over
+//! 210.5 at one book and Under 213.5 at another. If the result lands between the
+//! lines both bets win; otherwise one wins and the other loses.
+
+use crate::validation::{validate_probability, validate_stake};
+use crate::{Odds, OddsError};
+
+/// Calculates how to split a total stake across the two sides of a middle.
+///
+/// The stakes are chosen so that both sides return the same payout when only one
+/// of them wins: `low_stake * low_decimal == high_stake * high_decimal`. At
+/// standard prices this leaves a small guaranteed loss (the combined vig) when the
+/// middle misses, in exchange for a large win when the result lands in the middle.
+///
+/// # Arguments
+///
+/// * `low_odds` - The price of the bet on the lower line (e.g. Over 210.5)
+/// * `high_odds` - The price of the bet on the higher line (e.g. Under 213.5)
+/// * `total_stake` - The combined amount to wager (must be non-negative)
+///
+/// # Returns
+///
+/// Returns `Ok((low_stake, high_stake))`, or an `Err(OddsError)` if either price
+/// is invalid or the stake is negative.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::middle::middle_stakes;
+/// use odds_converter::Odds;
+///
+/// let (low, high) = middle_stakes(&Odds::new_decimal(2.0), &Odds::new_decimal(2.0), 100.0)
+///     .unwrap();
+/// assert_eq!((low, high), (50.0, 50.0));
+/// ```
+pub fn middle_stakes(
+    low_odds: &Odds,
+    high_odds: &Odds,
+    total_stake: f64,
+) -> Result<(f64, f64), OddsError> {
+    low_odds.validate()?;
+    high_odds.validate()?;
+    validate_stake(total_stake)?;
+
+    let low_decimal = low_odds.to_decimal()?;
+    let high_decimal = high_odds.to_decimal()?;
+    let combined = low_decimal + high_decimal;
+
+    Ok((
+        total_stake * high_decimal / combined,
+        total_stake * low_decimal / combined,
+    ))
+}
+
+/// Calculates the expected value of a middle staked with [`middle_stakes`].
+///
+/// When the result lands in the middle both bets win; otherwise exactly one wins
+/// and, because the stakes equalize payouts, the loss is the same either way. The
+/// expected value weights these two results by the probability of hitting the middle.
+///
+/// # Arguments
+///
+/// * `low_odds` - The price of the bet on the lower line
+/// * `high_odds` - The price of the bet on the higher line
+/// * `middle_prob` - Probability that the result lands between the lines (0 to 1)
+/// * `total_stake` - The combined amount to wager (must be non-negative)
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the expected profit, or an `Err(OddsError)` if any
+/// input is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::middle::middle_expected_value;
+/// use odds_converter::Odds;
+///
+/// let even = Odds::new_decimal(2.0);
+/// assert_eq!(middle_expected_value(&even, &even, 0.1, 100.0).unwrap(), 10.0);
+/// ```
+pub fn middle_expected_value(
+    low_odds: &Odds,
+    high_odds: &Odds,
+    middle_prob: f64,
+    total_stake: f64,
+) -> Result<f64, OddsError> {
+    validate_probability(middle_prob)?;
+    let (low_stake, high_stake) = middle_stakes(low_odds, high_odds, total_stake)?;
+
+    let low_return = low_stake * low_odds.to_decimal()?;
+    let high_return = high_stake * high_odds.to_decimal()?;
+    let hit = low_return + high_return - total_stake;
+    let miss = low_return - total_stake;
+
+    Ok(middle_prob * hit + (1.0 - middle_prob) * miss)
+}
@@ -0,0 +1,109 @@
+//! Standard normal distribution helpers.
+//!
+//! Point-spread models assume the final margin is normally distributed, so these
+//! approximations back the pricing functions in the `spread` module.
+
+use crate::math;
+
+/// Cumulative distribution function of the standard normal distribution.
+///
+/// Uses Hart's double-precision rational approximation as presented by West
+/// (2005), which is accurate to around 1e-14 and returns exactly 0.5 at zero.
+pub(crate) fn cdf(x: f64) -> f64 {
+    let x_abs = math::abs(x);
+    let tail = if x_abs > 37.0 {
+        0.0
+    } else {
+        let exponential = math::exp(-x_abs * x_abs / 2.0);
+        if x_abs < 7.07106781186547 {
+            let mut numerator = 3.52624965998911e-2 * x_abs + 0.700383064443688;
+            numerator = numerator * x_abs + 6.37396220353165;
+            numerator = numerator * x_abs + 33.912866078383;
+            numerator = numerator * x_abs + 112.079291497871;
+            numerator = numerator * x_abs + 221.213596169931;
+            numerator = numerator * x_abs + 220.206867912376;
+
+            let mut denominator = 8.83883476483184e-2 * x_abs + 1.75566716318264;
+            denominator = denominator * x_abs + 16.064177579207;
+            denominator = denominator * x_abs + 86.7807322029461;
+            denominator = denominator * x_abs + 296.564248779674;
+            denominator = denominator * x_abs + 637.333633378831;
+            denominator = denominator * x_abs + 793.826512519948;
+            denominator = denominator * x_abs + 440.413735824752;
+
+            exponential * numerator / denominator
+        } else {
+            let mut fraction = x_abs + 0.65;
+            fraction = x_abs + 4.0 / fraction;
+            fraction = x_abs + 3.0 / fraction;
+            fraction = x_abs + 2.0 / fraction;
+            fraction = x_abs + 1.0 / fraction;
+            exponential / fraction / 2.506628274631
+        }
+    };
+
+    if x > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// Inverse of the standard normal cumulative distribution function.
+///
+/// Uses Acklam's rational approximation (relative error below 1.15e-9), returning
+/// the matching infinity at the endpoints 0 and 1.
+pub(crate) fn inverse_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.383_577_518_672_69e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    if p < P_LOW {
+        let q = math::sqrt(-2.0 * math::ln(p));
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = math::sqrt(-2.0 * math::ln(1.0 - p));
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    }
+}
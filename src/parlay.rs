@@ -0,0 +1,139 @@
+//! Parlay (accumulator) calculations.
+//!
+//! This module contains helpers for deciding whether combining several selections
+//! into a single parlay is worthwhile compared to betting each leg on its own.
+
+use crate::validation::validate_probability;
+use crate::{Odds, OddsError};
+use alloc::format;
+use alloc::string::ToString;
+
+/// Calculates the fair parlay decimal odds for a set of independent legs.
+///
+/// The fair price is the reciprocal of the product of the true leg probabilities.
+/// A book's parlay price must exceed this threshold for the parlay to carry positive
+/// expected value, and therefore to be preferable to betting the legs straight.
+///
+/// # Arguments
+///
+/// * `leg_probs` - Your true win probability for each leg (each in `(0, 1]`)
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the break-even parlay decimal odds, or an
+/// `Err(OddsError)` if the slice is empty or any probability is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::parlay::parlay_vs_straight_threshold;
+///
+/// let threshold = parlay_vs_straight_threshold(&[0.5, 0.5]).unwrap();
+/// assert_eq!(threshold, 4.0);
+/// ```
+pub fn parlay_vs_straight_threshold(leg_probs: &[f64]) -> Result<f64, OddsError> {
+    if leg_probs.is_empty() {
+        return Err(OddsError::ValueOutOfRange(
+            "A parlay requires at least one leg".to_string(),
+        ));
+    }
+
+    let mut combined = 1.0;
+    for &probability in leg_probs {
+        validate_probability(probability)?;
+        if probability == 0.0 {
+            return Err(OddsError::ValueOutOfRange(
+                "Leg probability must be greater than 0".to_string(),
+            ));
+        }
+        combined *= probability;
+    }
+
+    Ok(1.0 / combined)
+}
+
+impl Odds {
+    /// Prices a parlay whose legs are correlated, such as a same-game parlay.
+    ///
+    /// Multiplying the leg prices assumes the legs are independent, which overstates
+    /// fair value when they tend to win together. This uses a simple interpolation
+    /// model on the joint win probability. Let `p` be the product of the leg implied
+    /// probabilities (the independent case):
+    ///
+    /// - A positive correlation moves `p` toward the smallest leg probability, the
+    ///   joint probability when the legs always win together (correlation 1).
+    /// - A negative correlation moves `p` toward `max(0, sum - (n - 1))`, the lowest
+    ///   joint probability the legs allow (correlation -1).
+    ///
+    /// The move is linear in the correlation, so 0.5 goes halfway to the bound. The
+    /// adjusted decimal odds are the reciprocal of the adjusted probability. This is
+    /// a single pairwise-style factor for the whole parlay, not a full correlation
+    /// matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `legs` - The price of each leg
+    /// * `correlation` - How strongly the legs move together, in `[-1, 1]`
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Odds)` containing the adjusted decimal odds, or an
+    /// `Err(OddsError)` if `legs` is empty, any leg is invalid, the correlation is
+    /// out of range, or the legs could never all win at this correlation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// let legs = [Odds::new_decimal(2.0), Odds::new_decimal(2.0)];
+    /// let independent = Odds::correlated_parlay(&legs, 0.0).unwrap();
+    /// assert!((independent.to_decimal().unwrap() - 4.0).abs() < 1e-12);
+    ///
+    /// // Positively correlated legs pay less than the plain product
+    /// let correlated = Odds::correlated_parlay(&legs, 0.5).unwrap();
+    /// assert!((correlated.to_decimal().unwrap() - 8.0 / 3.0).abs() < 1e-12);
+    /// ```
+    pub fn correlated_parlay(legs: &[Odds], correlation: f64) -> Result<Odds, OddsError> {
+        if legs.is_empty() {
+            return Err(OddsError::ValueOutOfRange(
+                "A parlay requires at least one leg".to_string(),
+            ));
+        }
+        if !correlation.is_finite() {
+            return Err(OddsError::InfiniteOrNaN);
+        }
+        if !(-1.0..=1.0).contains(&correlation) {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Correlation must be between -1 and 1, got: {}",
+                correlation
+            )));
+        }
+
+        let mut independent = 1.0;
+        let mut smallest = 1.0;
+        let mut sum = 0.0;
+        for leg in legs {
+            leg.validate()?;
+            let probability = leg.implied_probability()?;
+            independent *= probability;
+            smallest = f64::min(smallest, probability);
+            sum += probability;
+        }
+
+        let joint = if correlation >= 0.0 {
+            independent + correlation * (smallest - independent)
+        } else {
+            let lowest = f64::max(0.0, sum - (legs.len() - 1) as f64);
+            independent + correlation * (independent - lowest)
+        };
+        if joint <= 0.0 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "The legs cannot all win together at correlation {}",
+                correlation
+            )));
+        }
+
+        Odds::from_probability(joint)
+    }
+}
@@ -0,0 +1,51 @@
+//! Implied probability calculations over a set of prices.
+//!
+//! These functions total the implied probabilities of a slice of odds in one pass,
+//! for callers that need both the individual values and the market totals.
+
+use crate::conversions::implied_probabilities;
+use crate::{Odds, OddsError};
+use alloc::vec::Vec;
+
+/// Implied probabilities of a set of outcomes, as returned by [`market_summary`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketSummary {
+    /// Implied probability of each outcome, in input order.
+    pub per_outcome: Vec<f64>,
+    /// Sum of the implied probabilities.
+    pub total: f64,
+    /// Bookmaker margin, `total - 1`.
+    pub overround: f64,
+}
+
+/// Calculates the implied probability of each outcome and the market totals.
+///
+/// # Arguments
+///
+/// * `odds` - The price of each mutually exclusive outcome
+///
+/// # Returns
+///
+/// Returns `Ok(MarketSummary)`, or an `Err(OddsError)` if the slice is empty or
+/// any price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::probability::market_summary;
+/// use odds_converter::Odds;
+///
+/// let summary = market_summary(&[Odds::new_american(-110), Odds::new_american(-110)]).unwrap();
+/// assert_eq!(summary.per_outcome.len(), 2);
+/// assert!((summary.total - 1.0476).abs() < 1e-4);
+/// assert!((summary.overround - 0.0476).abs() < 1e-4);
+/// ```
+pub fn market_summary(odds: &[Odds]) -> Result<MarketSummary, OddsError> {
+    let per_outcome = implied_probabilities(odds)?;
+    let total: f64 = per_outcome.iter().sum();
+    Ok(MarketSummary {
+        per_outcome,
+        total,
+        overround: total - 1.0,
+    })
+}
@@ -0,0 +1,189 @@
+//! Multi-runner race models.
+//!
+//! These functions use the Harville model, which treats every finishing position as
+//! a fresh race among the runners still unplaced. Each remaining runner wins that
+//! "race" with probability proportional to its devigged win probability. The win
+//! odds are devigged by normalizing their implied probabilities to sum to 1.
+
+use crate::conversions::normalized_probabilities;
+use crate::{Odds, OddsError};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Number of finishing positions reported by [`harville_finish_probabilities`].
+const REPORTED_POSITIONS: usize = 3;
+
+/// Calculates each runner's probability of finishing 1st, 2nd and 3rd.
+///
+/// # Arguments
+///
+/// * `win_odds` - The win price of every runner in the field
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<Vec<f64>>)` with one entry per runner, in input order. Each entry
+/// holds the probabilities of finishing 1st, 2nd and 3rd (fewer when the field has
+/// fewer than three runners). Returns an `Err(OddsError)` if the field is empty or
+/// any price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::racing::harville_finish_probabilities;
+/// use odds_converter::Odds;
+///
+/// let field = [Odds::new_decimal(2.0), Odds::new_decimal(2.0)];
+/// let probabilities = harville_finish_probabilities(&field).unwrap();
+/// assert_eq!(probabilities[0], vec![0.5, 0.5]);
+/// ```
+pub fn harville_finish_probabilities(win_odds: &[Odds]) -> Result<Vec<Vec<f64>>, OddsError> {
+    let probabilities = normalized_probabilities(win_odds)?;
+    let positions = probabilities.len().min(REPORTED_POSITIONS);
+
+    Ok((0..probabilities.len())
+        .map(|runner| {
+            (0..positions)
+                .map(|position| finish_probability(&probabilities, runner, position))
+                .collect()
+        })
+        .collect())
+}
+
+/// Calculates fair odds for an exacta (two runners finishing 1st and 2nd in order).
+///
+/// Under the Harville model the probability is `p_first * p_second / (1 - p_first)`.
+///
+/// # Arguments
+///
+/// * `win_odds` - The win price of every runner in the field
+/// * `first` - Index of the runner to finish first
+/// * `second` - Index of the runner to finish second
+///
+/// # Returns
+///
+/// Returns `Ok(Odds)` containing fair decimal odds, or an `Err(OddsError)` if the
+/// indices are out of range or equal, or any price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::racing::exacta_odds;
+/// use odds_converter::Odds;
+///
+/// let field = [Odds::new_decimal(2.0), Odds::new_decimal(4.0), Odds::new_decimal(4.0)];
+/// let exacta = exacta_odds(&field, 0, 1).unwrap();
+/// assert!((exacta.to_decimal().unwrap() - 4.0).abs() < 1e-9);
+/// ```
+pub fn exacta_odds(win_odds: &[Odds], first: usize, second: usize) -> Result<Odds, OddsError> {
+    validate_runner(win_odds, first)?;
+    validate_runner(win_odds, second)?;
+    if first == second {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Exacta runners must be different, got {} twice",
+            first
+        )));
+    }
+
+    let probabilities = normalized_probabilities(win_odds)?;
+    let p_first = probabilities[first];
+    Odds::from_probability(p_first * probabilities[second] / (1.0 - p_first))
+}
+
+/// Calculates the probability that a runner finishes in the top `top_n` places.
+///
+/// This is the fair probability for place and show markets, computed by summing the
+/// runner's Harville probabilities of finishing in each of the first `top_n` places.
+///
+/// # Arguments
+///
+/// * `win_odds` - The win price of every runner in the field
+/// * `runner` - Index of the runner
+/// * `top_n` - Number of places paid, between 1 and the size of the field
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the place probability, or an `Err(OddsError)` if the
+/// runner index or `top_n` is out of range, or any price is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::racing::place_probability;
+/// use odds_converter::Odds;
+///
+/// let field = [Odds::new_decimal(2.0), Odds::new_decimal(4.0), Odds::new_decimal(4.0)];
+/// let top_two = place_probability(&field, 1, 2).unwrap();
+/// assert!((top_two - (0.25 + 0.5 * 0.25 / 0.5 + 0.25 * 0.25 / 0.75)).abs() < 1e-9);
+/// ```
+pub fn place_probability(win_odds: &[Odds], runner: usize, top_n: usize) -> Result<f64, OddsError> {
+    validate_runner(win_odds, runner)?;
+    if top_n == 0 || top_n > win_odds.len() {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Places paid must be between 1 and {}, got: {}",
+            win_odds.len(),
+            top_n
+        )));
+    }
+
+    let probabilities = normalized_probabilities(win_odds)?;
+    Ok((0..top_n)
+        .map(|position| finish_probability(&probabilities, runner, position))
+        .sum())
+}
+
+/// Validates that `runner` indexes into the field.
+fn validate_runner(win_odds: &[Odds], runner: usize) -> Result<(), OddsError> {
+    if runner < win_odds.len() {
+        Ok(())
+    } else {
+        Err(OddsError::ValueOutOfRange(format!(
+            "Runner index {} is out of range for a field of {}",
+            runner,
+            win_odds.len()
+        )))
+    }
+}
+
+/// Harville probability that `runner` finishes in zero-based `position`.
+///
+/// Enumerates every ordered sequence of other runners filling the places ahead of
+/// it, so the cost grows as `n^position`. This is intended for the first few places.
+fn finish_probability(probabilities: &[f64], runner: usize, position: usize) -> f64 {
+    let mut placed = vec![false; probabilities.len()];
+    placed[runner] = true;
+    sum_over_orderings(probabilities, runner, position, &mut placed, 1.0, 1.0)
+}
+
+/// Recursively places `remaining` runners ahead of `runner` and sums the paths.
+fn sum_over_orderings(
+    probabilities: &[f64],
+    runner: usize,
+    remaining: usize,
+    placed: &mut [bool],
+    path_probability: f64,
+    unplaced_mass: f64,
+) -> f64 {
+    if remaining == 0 {
+        return path_probability * probabilities[runner] / unplaced_mass;
+    }
+
+    let mut total = 0.0;
+    for other in 0..probabilities.len() {
+        if placed[other] {
+            continue;
+        }
+        let p = probabilities[other];
+        placed[other] = true;
+        total += sum_over_orderings(
+            probabilities,
+            runner,
+            remaining - 1,
+            placed,
+            path_probability * p / unplaced_mass,
+            unplaced_mass - p,
+        );
+        placed[other] = false;
+    }
+    total
+}
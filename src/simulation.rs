@@ -0,0 +1,151 @@
+//! Synthetic odds for simulation and backtesting.
+//!
+//! Random numbers come from a small seeded generator built into the crate, so a
+//! given seed always produces the same odds on every platform.
+
+use crate::validation::validate_probability;
+use crate::{math, Odds, OddsError};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// Allowed distance between the sum of the true probabilities and 1.
+const PROBABILITY_SUM_TOLERANCE: f64 = 1e-6;
+
+/// Smallest implied probability produced, capping simulated prices at decimal odds
+/// of 1000.0.
+const MIN_SIMULATED_PROBABILITY: f64 = 0.001;
+
+/// Largest implied probability produced, so every price still pays something.
+const MAX_SIMULATED_PROBABILITY: f64 = 0.999;
+
+/// Generates a bookmaker's American odds for a market with known true probabilities.
+///
+/// Each true probability is perturbed by Gaussian noise with standard deviation
+/// `noise_std`, the perturbed probabilities are renormalized to sum to 1, and the
+/// margin is applied proportionally so the implied probabilities sum to
+/// `1 + margin`. Implied probabilities are clamped to `[0.001, 0.999]` before being
+/// rounded to whole American odds, so the realized overround can differ slightly
+/// from `margin`.
+///
+/// The same inputs and `seed` always produce the same odds.
+///
+/// # Arguments
+///
+/// * `true_probs` - The true probability of each outcome (must sum to 1)
+/// * `margin` - The bookmaker's overround, such as 0.05 for 5% (must be non-negative)
+/// * `noise_std` - Standard deviation of the noise added to each probability (must
+///   be non-negative)
+/// * `seed` - Seed for the random number generator
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<Odds>)` with American odds for each outcome, in input order, or
+/// an `Err(OddsError)` if the inputs are invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::simulation::simulate_market_odds;
+///
+/// let odds = simulate_market_odds(&[0.5, 0.5], 0.05, 0.0, 7).unwrap();
+/// assert_eq!(odds[0].to_american().unwrap(), -111);
+/// assert_eq!(odds, simulate_market_odds(&[0.5, 0.5], 0.05, 0.0, 7).unwrap());
+/// ```
+pub fn simulate_market_odds(
+    true_probs: &[f64],
+    margin: f64,
+    noise_std: f64,
+    seed: u64,
+) -> Result<Vec<Odds>, OddsError> {
+    if true_probs.is_empty() {
+        return Err(OddsError::ValueOutOfRange(
+            "At least one outcome is required".to_string(),
+        ));
+    }
+    for &probability in true_probs {
+        validate_probability(probability)?;
+    }
+    let total: f64 = true_probs.iter().sum();
+    if math::abs(total - 1.0) > PROBABILITY_SUM_TOLERANCE {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "True probabilities must sum to 1, got: {}",
+            total
+        )));
+    }
+    validate_non_negative("Margin", margin)?;
+    validate_non_negative("Noise standard deviation", noise_std)?;
+
+    let mut rng = SplitMix64::new(seed);
+    let noisy: Vec<f64> = true_probs
+        .iter()
+        .map(|&probability| {
+            (probability + noise_std * rng.next_standard_normal()).max(MIN_SIMULATED_PROBABILITY)
+        })
+        .collect();
+    let noisy_total: f64 = noisy.iter().sum();
+
+    noisy
+        .iter()
+        .map(|&probability| {
+            let implied = (probability / noisy_total * (1.0 + margin))
+                .clamp(MIN_SIMULATED_PROBABILITY, MAX_SIMULATED_PROBABILITY);
+            Ok(Odds::new_american(
+                Odds::from_probability(implied)?.to_american()?,
+            ))
+        })
+        .collect()
+}
+
+/// Validates that a simulation parameter is finite and non-negative.
+fn validate_non_negative(name: &str, value: f64) -> Result<(), OddsError> {
+    if !value.is_finite() {
+        Err(OddsError::InfiniteOrNaN)
+    } else if value < 0.0 {
+        Err(OddsError::NegativeValue(format!(
+            "{} cannot be negative, got: {}",
+            name, value
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// The SplitMix64 generator (Steele, Lea and Flood, 2014).
+///
+/// It is small, fast and passes standard statistical test suites, which is plenty
+/// for generating test data.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniform sample from `[0, 1)` using the top 53 bits.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a standard normal sample using Marsaglia's polar method.
+    fn next_standard_normal(&mut self) -> f64 {
+        loop {
+            let u = 2.0 * self.next_f64() - 1.0;
+            let v = 2.0 * self.next_f64() - 1.0;
+            let s = u * u + v * v;
+            if s > 0.0 && s < 1.0 {
+                return u * math::sqrt(-2.0 * math::ln(s) / s);
+            }
+        }
+    }
+}
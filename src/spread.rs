@@ -0,0 +1,172 @@
+//! Point-spread pricing under a normal margin model.
+//!
+//! These functions assume the favorite's final margin of victory is normally
+//! distributed with a known standard deviation. Throughout the module `spread` is
+//! the favorite's expected margin relative to the line being priced: for a
+//! handicap line it is the expected margin minus the points given, and for a
+//! moneyline (a line of zero) it is simply the expected margin of victory.
+
+use crate::conversions::normalized_probabilities;
+use crate::{normal, Odds, OddsError};
+use alloc::format;
+
+/// Calculates fair cover odds for the favorite and underdog of a point spread.
+///
+/// The favorite covers when its margin beats the line, which under the normal model
+/// happens with probability `Φ(spread / sigma)`. The underdog covers otherwise.
+///
+/// # Arguments
+///
+/// * `spread` - The favorite's expected margin relative to the line, in points
+/// * `sigma` - Standard deviation of the margin, in points (must be positive)
+///
+/// # Returns
+///
+/// Returns `Ok((favorite, underdog))` containing fair decimal odds for each side,
+/// or an `Err(OddsError)` if the inputs are invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::spread::spread_to_odds;
+///
+/// let (favorite, underdog) = spread_to_odds(0.0, 13.5).unwrap();
+/// assert_eq!(favorite.to_decimal().unwrap(), 2.0);
+/// assert_eq!(underdog.to_decimal().unwrap(), 2.0);
+/// ```
+pub fn spread_to_odds(spread: f64, sigma: f64) -> Result<(Odds, Odds), OddsError> {
+    validate_spread(spread)?;
+    validate_sigma(sigma)?;
+
+    let favorite = normal::cdf(spread / sigma);
+    let underdog = 1.0 - favorite;
+    if favorite <= 0.0 || underdog <= 0.0 {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Spread {} is too extreme for sigma {}",
+            spread, sigma
+        )));
+    }
+
+    Ok((
+        Odds::new_decimal(1.0 / favorite),
+        Odds::new_decimal(1.0 / underdog),
+    ))
+}
+
+/// Calculates the point spread implied by a two-way moneyline.
+///
+/// This is the inverse of [`spread_to_odds`]. The moneyline is devigged to obtain
+/// the favorite's fair win probability `p`, and the implied spread is the expected
+/// margin of victory `sigma * Φ⁻¹(p)`. If the side passed as `fav` is actually the
+/// longer price, the returned spread is negative.
+///
+/// # Arguments
+///
+/// * `fav` - The favorite's moneyline price
+/// * `dog` - The underdog's moneyline price
+/// * `sigma` - Standard deviation of the margin, in points (must be positive)
+///
+/// # Returns
+///
+/// Returns `Ok(f64)` containing the favorite's expected margin in points, or an
+/// `Err(OddsError)` if either price or sigma is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::spread::moneyline_to_spread;
+/// use odds_converter::Odds;
+///
+/// let spread = moneyline_to_spread(&Odds::new_american(-110), &Odds::new_american(-110), 13.5)
+///     .unwrap();
+/// assert!(spread.abs() < 1e-9);
+/// ```
+pub fn moneyline_to_spread(fav: &Odds, dog: &Odds, sigma: f64) -> Result<f64, OddsError> {
+    validate_sigma(sigma)?;
+
+    let probabilities = normalized_probabilities(&[*fav, *dog])?;
+    Ok(sigma * normal::inverse_cdf(probabilities[0]))
+}
+
+/// Calculates fair odds that the winning margin falls within a band, whichever
+/// team wins.
+///
+/// Margin-band props such as "either team to win by 1-6 points" pay out on the
+/// absolute margin of victory. With the favorite's margin `M` normally distributed
+/// around `spread`, the probability combines both teams' bands:
+/// `P(band_low <= M <= band_high) + P(-band_high <= M <= -band_low)`.
+///
+/// # Arguments
+///
+/// * `spread` - The favorite's expected margin of victory, in points
+/// * `band_low` - Smallest winning margin in the band (must be non-negative)
+/// * `band_high` - Largest winning margin in the band (must be at least `band_low`)
+/// * `sigma` - Standard deviation of the margin, in points (must be positive)
+///
+/// # Returns
+///
+/// Returns `Ok(Odds)` containing fair decimal odds, or an `Err(OddsError)` if the
+/// band is invalid or so narrow that it has no probability, or sigma is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::spread::margin_band_odds;
+///
+/// let close_game = margin_band_odds(3.0, 0.0, 6.5, 13.5).unwrap();
+/// let blowout = margin_band_odds(3.0, 20.5, 100.0, 13.5).unwrap();
+/// assert!(close_game.to_decimal().unwrap() < blowout.to_decimal().unwrap());
+/// ```
+pub fn margin_band_odds(
+    spread: f64,
+    band_low: f64,
+    band_high: f64,
+    sigma: f64,
+) -> Result<Odds, OddsError> {
+    validate_spread(spread)?;
+    validate_spread(band_low)?;
+    validate_spread(band_high)?;
+    validate_sigma(sigma)?;
+    if band_low < 0.0 {
+        return Err(OddsError::NegativeValue(format!(
+            "Band must start at a non-negative margin, got: {}",
+            band_low
+        )));
+    }
+    if band_low > band_high {
+        return Err(OddsError::ValueOutOfRange(format!(
+            "Band low {} must not exceed band high {}",
+            band_low, band_high
+        )));
+    }
+
+    let band = |low: f64, high: f64| {
+        normal::cdf((high - spread) / sigma) - normal::cdf((low - spread) / sigma)
+    };
+    let probability = band(band_low, band_high) + band(-band_high, -band_low);
+
+    Odds::from_probability(probability.min(1.0))
+}
+
+/// Validates that a spread is a finite number of points.
+fn validate_spread(spread: f64) -> Result<(), OddsError> {
+    if spread.is_finite() {
+        Ok(())
+    } else {
+        Err(OddsError::InfiniteOrNaN)
+    }
+}
+
+/// Validates that a margin standard deviation is finite and positive.
+fn validate_sigma(sigma: f64) -> Result<(), OddsError> {
+    if !sigma.is_finite() {
+        Err(OddsError::InfiniteOrNaN)
+    } else if sigma <= 0.0 {
+        Err(OddsError::ValueOutOfRange(format!(
+            "Sigma must be greater than 0, got: {}",
+            sigma
+        )))
+    } else {
+        Ok(())
+    }
+}
@@ -1,3 +1,8 @@
+use crate::{math, OddsError};
+use alloc::format;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
 /// Represents the different formats of betting odds.
 ///
 /// Betting odds can be expressed in three main formats, each common in different regions:
@@ -14,7 +19,7 @@
 /// let decimal = OddsFormat::Decimal(2.5);      // 2.50 decimal odds  
 /// let fractional = OddsFormat::Fractional(3, 2); // 3/2 fractional odds
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OddsFormat {
     /// American odds format (also known as moneyline odds).
     ///
@@ -44,9 +49,51 @@ pub enum OddsFormat {
     /// # Examples
     /// - `Fractional(3, 2)` means 3:2 odds (bet $2 to win $3 profit)
     /// - `Fractional(1, 2)` means 1:2 odds (bet $2 to win $1 profit)
+    /// - `Fractional(0, 1)` pays no profit (decimal 1.0). It is valid and displays
+    ///   as "0/1". Use [`OddsFormat::StartingPrice`] for a price not yet known.
+    ///
+    /// Each part can hold values up to `u32::MAX`, far beyond any quoted price, and
+    /// [`Odds::validate`] accepts parts up to 100000 so longshots such as 5000/1 or
+    /// 100000/1 are supported.
     Fractional(u32, u32),
+
+    /// Starting price ("SP"), a horse racing price that is not yet known.
+    ///
+    /// The price is set when the race starts, so until then there is nothing to
+    /// convert. Conversions and [`Odds::validate`] return
+    /// [`OddsError::StartingPriceUndetermined`], while parsing and display work
+    /// as usual with the text "SP".
+    StartingPrice,
+}
+
+/// The name of an odds format, without a value.
+///
+/// Used to tell [`Odds::from_str_as`] which format to parse when it is known ahead
+/// of time, for example per column of an import file.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Odds, OddsFormat, OddsFormatKind};
+///
+/// let odds = Odds::from_str_as("100", OddsFormatKind::Decimal).unwrap();
+/// assert_eq!(odds.format(), &OddsFormat::Decimal(100.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OddsFormatKind {
+    /// American odds, a whole number such as "+150" or "-110".
+    American,
+    /// Decimal odds, such as "2.50".
+    Decimal,
+    /// Fractional odds in "num/den" form, such as "3/2".
+    Fractional,
 }
 
+/// Scale applied to decimal odds before rounding to form the equality key.
+///
+/// A scale of 1000 quantizes odds to three decimal places.
+const CANONICAL_DECIMAL_SCALE: f64 = 1000.0;
+
 /// The main odds structure that can hold any of the three odds formats.
 ///
 /// This struct provides a unified interface for working with different odds formats,
@@ -66,7 +113,36 @@ pub enum OddsFormat {
 /// assert_eq!(american.to_decimal().unwrap(), 2.5);
 /// assert_eq!(decimal.to_american().unwrap(), 150);
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// # Equality and hashing
+///
+/// Two `Odds` compare equal when their decimal equivalents agree after rounding to
+/// three decimal places, regardless of the format they are stored in. This means
+/// American -110, decimal 1.909 and fractional 10/11 are all equal and hash
+/// identically, which makes `Odds` suitable as a `HashMap` or `HashSet` key.
+/// Odds that cannot be converted to a finite decimal (such as a zero denominator)
+/// fall back to comparing their stored format exactly.
+///
+/// ```
+/// use odds_converter::Odds;
+/// use std::collections::HashSet;
+///
+/// let lines: HashSet<Odds> = [
+///     Odds::new_american(-110),
+///     Odds::new_decimal(1.909),
+///     Odds::new_fractional(10, 11),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(lines.len(), 1);
+/// ```
+///
+/// # Debug output
+///
+/// The `Debug` output shows the decimal equivalent next to the stored format, as
+/// in `Odds { format: Fractional(3, 2), decimal: 2.5 }`. Odds that cannot be
+/// converted show `decimal: <invalid>`.
+#[derive(Clone, Copy)]
 pub struct Odds {
     pub(crate) format: OddsFormat,
 }
@@ -95,19 +171,52 @@ impl Odds {
     /// let underdog = Odds::new_american(200);   // Bet $100 to win $200
     /// let edge_case_pos = Odds::new_american(50);   // Automatically becomes -200
     /// let edge_case_neg = Odds::new_american(-50);  // Automatically becomes +200
+    ///
+    /// const PICK_EM: Odds = Odds::new_american(100);
     /// ```
-    pub fn new_american(value: i32) -> Self {
+    pub const fn new_american(value: i32) -> Self {
         use crate::conversions::normalize_american_odds;
         Self {
             format: OddsFormat::American(normalize_american_odds(value)),
         }
     }
 
+    /// Creates new American odds exactly as given, without normalization.
+    ///
+    /// Unlike [`Odds::new_american`], values between -99 and +99 are stored verbatim
+    /// rather than being converted to their standard representation. This is useful
+    /// when recording prices exactly as a sportsbook published them. Call
+    /// [`Odds::validate`] to reject values that are truly invalid, such as zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The American odds value, stored as-is
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let raw = Odds::new_american_raw(50);
+    /// assert_eq!(raw.format(), &OddsFormat::American(50));
+    ///
+    /// let normalized = Odds::new_american(50);
+    /// assert_eq!(normalized.format(), &OddsFormat::American(-200));
+    /// ```
+    pub const fn new_american_raw(value: i32) -> Self {
+        Self {
+            format: OddsFormat::American(value),
+        }
+    }
+
     /// Creates new odds in decimal format.
     ///
     /// Decimal odds represent the total return (including original stake) for a unit bet.
     /// A value of 2.0 means even odds (50% probability).
     ///
+    /// Like the other constructors this is a `const fn`, so odds can be declared in
+    /// constants and static tables.
+    ///
     /// # Arguments
     ///
     /// * `value` - The decimal odds value (must be >= 1.0)
@@ -120,8 +229,10 @@ impl Odds {
     /// let even_odds = Odds::new_decimal(2.0);    // 50% probability
     /// let long_odds = Odds::new_decimal(5.0);    // 20% probability
     /// let short_odds = Odds::new_decimal(1.25);  // 80% probability
+    ///
+    /// const STANDARD_VIG: Odds = Odds::new_decimal(1.909);
     /// ```
-    pub fn new_decimal(value: f64) -> Self {
+    pub const fn new_decimal(value: f64) -> Self {
         Self {
             format: OddsFormat::Decimal(value),
         }
@@ -146,12 +257,79 @@ impl Odds {
     /// let evens = Odds::new_fractional(1, 1);         // Even money
     /// let odds_on = Odds::new_fractional(1, 4);       // 1:4 odds (short odds)
     /// ```
-    pub fn new_fractional(numerator: u32, denominator: u32) -> Self {
+    pub const fn new_fractional(numerator: u32, denominator: u32) -> Self {
         Self {
             format: OddsFormat::Fractional(numerator, denominator),
         }
     }
 
+    /// Creates starting price ("SP") odds, whose price is not yet known.
+    ///
+    /// These act as a placeholder in data models: they parse from and display as
+    /// "SP", but any conversion returns
+    /// [`OddsError::StartingPriceUndetermined`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsError};
+    ///
+    /// let sp = Odds::starting_price();
+    /// assert_eq!(sp.to_string(), "SP");
+    /// assert_eq!(sp.to_decimal(), Err(OddsError::StartingPriceUndetermined));
+    /// ```
+    pub const fn starting_price() -> Self {
+        Self {
+            format: OddsFormat::StartingPrice,
+        }
+    }
+
+    /// Returns true if these are starting price ("SP") odds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::Odds;
+    ///
+    /// assert!("SP".parse::<Odds>().unwrap().is_starting_price());
+    /// assert!(!Odds::new_decimal(2.0).is_starting_price());
+    /// ```
+    pub const fn is_starting_price(&self) -> bool {
+        matches!(self.format, OddsFormat::StartingPrice)
+    }
+
+    /// Creates decimal odds from an implied probability.
+    ///
+    /// The resulting decimal odds are `1 / probability`, so a probability of 0.5
+    /// gives even money (2.0) and a probability of 1.0 gives 1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `probability` - The implied probability (must be in `(0, 1]`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let odds = Odds::from_probability(0.25).unwrap();
+    /// assert_eq!(odds.format(), &OddsFormat::Decimal(4.0));
+    /// assert!(Odds::from_probability(0.0).is_err());
+    /// ```
+    pub fn from_probability(probability: f64) -> Result<Self, OddsError> {
+        if !probability.is_finite() {
+            return Err(OddsError::InfiniteOrNaN);
+        }
+        if probability <= 0.0 || probability > 1.0 {
+            return Err(OddsError::ValueOutOfRange(format!(
+                "Probability must be greater than 0 and at most 1, got: {}",
+                probability
+            )));
+        }
+
+        Ok(Self::new_decimal(1.0 / probability))
+    }
+
     /// Returns a reference to the underlying odds format.
     ///
     /// This allows you to inspect the specific format and value of the odds
@@ -167,9 +345,244 @@ impl Odds {
     ///     OddsFormat::American(value) => println!("American odds: {}", value),
     ///     OddsFormat::Decimal(value) => println!("Decimal odds: {}", value),
     ///     OddsFormat::Fractional(num, den) => println!("Fractional odds: {}/{}", num, den),
+    ///     OddsFormat::StartingPrice => println!("Starting price"),
     /// }
     /// ```
-    pub fn format(&self) -> &OddsFormat {
+    pub const fn format(&self) -> &OddsFormat {
         &self.format
     }
 }
+
+/// Defaults to even money, stored as decimal odds of 2.0.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Odds, OddsFormat};
+///
+/// assert_eq!(Odds::default().format(), &OddsFormat::Decimal(2.0));
+/// ```
+impl Default for Odds {
+    fn default() -> Self {
+        Self::new_decimal(2.0)
+    }
+}
+
+impl Odds {
+    /// Returns the quantized decimal value used for equality and hashing.
+    ///
+    /// Returns `None` when the odds cannot be converted to a finite decimal.
+    fn canonical_key(&self) -> Option<i64> {
+        match self.to_decimal() {
+            Ok(decimal) if decimal.is_finite() => {
+                Some(math::round(decimal * CANONICAL_DECIMAL_SCALE) as i64)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true when both formats are identical, comparing floats bitwise.
+    fn structurally_identical(&self, other: &Odds) -> bool {
+        match (&self.format, &other.format) {
+            (OddsFormat::American(a), OddsFormat::American(b)) => a == b,
+            (OddsFormat::Decimal(a), OddsFormat::Decimal(b)) => a.to_bits() == b.to_bits(),
+            (OddsFormat::Fractional(a_num, a_den), OddsFormat::Fractional(b_num, b_den)) => {
+                a_num == b_num && a_den == b_den
+            }
+            (OddsFormat::StartingPrice, OddsFormat::StartingPrice) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for Odds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("Odds");
+        debug.field("format", &self.format);
+        match self.to_decimal() {
+            Ok(decimal) => debug.field("decimal", &decimal),
+            Err(_) => debug.field("decimal", &format_args!("<invalid>")),
+        };
+        debug.finish()
+    }
+}
+
+impl PartialEq for Odds {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.canonical_key(), other.canonical_key()) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => self.structurally_identical(other),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Odds {}
+
+impl Hash for Odds {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.canonical_key() {
+            Some(key) => {
+                0u8.hash(state);
+                key.hash(state);
+            }
+            None => {
+                1u8.hash(state);
+                match &self.format {
+                    OddsFormat::American(value) => (0u8, *value as u64, 0u64).hash(state),
+                    OddsFormat::Decimal(value) => (1u8, value.to_bits(), 0u64).hash(state),
+                    OddsFormat::Fractional(num, den) => (2u8, *num as u64, *den as u64).hash(state),
+                    OddsFormat::StartingPrice => (3u8, 0u64, 0u64).hash(state),
+                }
+            }
+        }
+    }
+}
+
+/// Validated American odds, for APIs that require this format at the type level.
+///
+/// Values between -99 and +99 are normalized as in [`Odds::new_american`].
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{AmericanOdds, Odds};
+///
+/// let american = AmericanOdds::new(-110).unwrap();
+/// let odds: Odds = american.into();
+/// assert_eq!(AmericanOdds::try_from(odds).unwrap(), american);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmericanOdds(i32);
+
+impl AmericanOdds {
+    /// Creates validated American odds.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(AmericanOdds)`, or an `Err(OddsError)` if the value fails
+    /// [`Odds::validate`].
+    pub fn new(value: i32) -> Result<Self, OddsError> {
+        let odds = Odds::new_american(value);
+        odds.validate()?;
+        odds.to_american().map(Self)
+    }
+
+    /// Returns the American odds value.
+    pub const fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+/// Validated decimal odds, for APIs that require this format at the type level.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{DecimalOdds, Odds};
+///
+/// let decimal = DecimalOdds::try_from(Odds::new_american(150)).unwrap();
+/// assert_eq!(decimal.value(), 2.5);
+/// assert!(DecimalOdds::new(0.5).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecimalOdds(f64);
+
+impl DecimalOdds {
+    /// Creates validated decimal odds.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DecimalOdds)`, or an `Err(OddsError)` if the value fails
+    /// [`Odds::validate`].
+    pub fn new(value: f64) -> Result<Self, OddsError> {
+        Odds::new_decimal(value).validate()?;
+        Ok(Self(value))
+    }
+
+    /// Returns the decimal odds value.
+    pub const fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+/// Validated fractional odds, for APIs that require this format at the type level.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{FractionalOdds, Odds};
+///
+/// let fractional = FractionalOdds::try_from(Odds::new_decimal(2.5)).unwrap();
+/// assert_eq!(fractional.value(), (3, 2));
+/// assert!(FractionalOdds::new(3, 0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FractionalOdds(u32, u32);
+
+impl FractionalOdds {
+    /// Creates validated fractional odds.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(FractionalOdds)`, or an `Err(OddsError)` if the value fails
+    /// [`Odds::validate`].
+    pub fn new(numerator: u32, denominator: u32) -> Result<Self, OddsError> {
+        Odds::new_fractional(numerator, denominator).validate()?;
+        Ok(Self(numerator, denominator))
+    }
+
+    /// Returns the fractional odds as `(numerator, denominator)`.
+    pub const fn value(&self) -> (u32, u32) {
+        (self.0, self.1)
+    }
+}
+
+impl From<AmericanOdds> for Odds {
+    fn from(odds: AmericanOdds) -> Self {
+        Odds::new_american(odds.0)
+    }
+}
+
+impl From<DecimalOdds> for Odds {
+    fn from(odds: DecimalOdds) -> Self {
+        Odds::new_decimal(odds.0)
+    }
+}
+
+impl From<FractionalOdds> for Odds {
+    fn from(odds: FractionalOdds) -> Self {
+        Odds::new_fractional(odds.0, odds.1)
+    }
+}
+
+/// Converts odds in any format to American odds, as with [`Odds::to_american`].
+impl TryFrom<Odds> for AmericanOdds {
+    type Error = OddsError;
+
+    fn try_from(odds: Odds) -> Result<Self, Self::Error> {
+        odds.validate()?;
+        AmericanOdds::new(odds.to_american()?)
+    }
+}
+
+/// Converts odds in any format to decimal odds, as with [`Odds::to_decimal`].
+impl TryFrom<Odds> for DecimalOdds {
+    type Error = OddsError;
+
+    fn try_from(odds: Odds) -> Result<Self, Self::Error> {
+        odds.validate()?;
+        DecimalOdds::new(odds.to_decimal()?)
+    }
+}
+
+/// Converts odds in any format to fractional odds, as with [`Odds::to_fractional`].
+impl TryFrom<Odds> for FractionalOdds {
+    type Error = OddsError;
+
+    fn try_from(odds: Odds) -> Result<Self, Self::Error> {
+        odds.validate()?;
+        let (numerator, denominator) = odds.to_fractional()?;
+        FractionalOdds::new(numerator, denominator)
+    }
+}
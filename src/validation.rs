@@ -4,17 +4,102 @@
 //! valid and within reasonable ranges for practical betting scenarios.
 
 use crate::{Odds, OddsError, OddsFormat};
+use alloc::format;
+use alloc::string::ToString;
 
-impl Odds {
-    /// Validates that the odds are mathematically correct and within reasonable ranges.
+/// Largest decimal odds accepted by default.
+const DEFAULT_MAX_DECIMAL: f64 = 100_001.0;
+
+/// Largest American odds magnitude accepted by default.
+const DEFAULT_MAX_AMERICAN_MAGNITUDE: u32 = 10_000_000;
+
+/// Largest fractional numerator or denominator accepted by default.
+const DEFAULT_MAX_FRACTIONAL_PART: u32 = 100_000;
+
+/// Implied probability below which [`Odds::validate_with_severity`] reports a
+/// longshot as extreme (decimal odds above 100.0).
+const EXTREME_LONGSHOT_PROBABILITY: f64 = 0.01;
+
+/// Implied probability above which [`Odds::validate_with_severity`] reports a
+/// heavy favorite as extreme (decimal odds below about 1.0101).
+const EXTREME_FAVORITE_PROBABILITY: f64 = 0.99;
+
+/// How unusual a structurally valid price is, as returned by
+/// [`Odds::validate_with_severity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationLevel {
+    /// A typical price, with an implied probability between 1% and 99%.
+    Normal,
+    /// A valid but unusual price: an extreme longshot or favorite, or outside the
+    /// default [`Validator`] limits.
+    Extreme,
+}
+
+/// Configurable limits for validating odds.
+///
+/// [`Odds::validate`] uses the default limits: decimal odds up to 100001.0, American
+/// odds within ±10000000, and fractional numerators and denominators up to 100000.
+/// The limits are consistent, so the longest valid fraction (100000/1) converts to
+/// decimal and American odds that are also valid.
+/// Build a `Validator` to loosen or tighten these for a particular market. The
+/// structural checks (non-zero American odds, finite decimal odds of at least 1.0,
+/// non-zero denominators) always apply.
+///
+/// A zero fractional numerator (`0/1`) is valid and means zero profit (decimal odds
+/// of 1.0). A starting price that has not been returned yet should be stored as
+/// [`OddsFormat::StartingPrice`](crate::OddsFormat::StartingPrice) instead.
+///
+/// # Examples
+///
+/// ```
+/// use odds_converter::{Odds, Validator};
+///
+/// let longshot = Odds::new_decimal(500_000.0);
+/// assert!(longshot.validate().is_err());
+///
+/// let exotic = Validator::new().max_decimal(1_000_000.0);
+/// assert!(exotic.validate(&longshot).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Validator {
+    max_decimal: f64,
+    max_american_magnitude: u32,
+    max_fractional_part: u32,
+}
+
+impl Validator {
+    /// Creates a validator with the default limits used by [`Odds::validate`].
+    pub const fn new() -> Self {
+        Self {
+            max_decimal: DEFAULT_MAX_DECIMAL,
+            max_american_magnitude: DEFAULT_MAX_AMERICAN_MAGNITUDE,
+            max_fractional_part: DEFAULT_MAX_FRACTIONAL_PART,
+        }
+    }
+
+    /// Sets the largest decimal odds to accept.
+    pub fn max_decimal(mut self, max_decimal: f64) -> Self {
+        self.max_decimal = max_decimal;
+        self
+    }
+
+    /// Sets the largest American odds magnitude to accept, for both signs.
+    pub fn max_american_magnitude(mut self, max_american_magnitude: u32) -> Self {
+        self.max_american_magnitude = max_american_magnitude;
+        self
+    }
+
+    /// Sets the largest fractional numerator or denominator to accept.
+    pub fn max_fractional_part(mut self, max_fractional_part: u32) -> Self {
+        self.max_fractional_part = max_fractional_part;
+        self
+    }
+
+    /// Validates odds against this validator's limits.
     ///
-    /// This method checks that odds values make mathematical sense and are within
-    /// practical limits for real-world betting scenarios. It validates:
+    /// # Arguments
     ///
-    /// - American odds are not zero
-    /// - Decimal odds are >= 1.0 and finite
-    /// - Fractional odds don't have zero denominators
-    /// - All odds are within reasonable ranges
+    /// * `odds` - The odds to check
     ///
     /// # Returns
     ///
@@ -24,22 +109,20 @@ impl Odds {
     /// # Examples
     ///
     /// ```
-    /// use odds_converter::{Odds, OddsError};
-    ///
-    /// let valid_odds = Odds::new_decimal(2.5);
-    /// assert!(valid_odds.validate().is_ok());
+    /// use odds_converter::{Odds, Validator};
     ///
-    /// let invalid_odds = Odds::new_decimal(0.5);
-    /// assert!(invalid_odds.validate().is_err());
+    /// let strict = Validator::new().max_american_magnitude(5000);
+    /// assert!(strict.validate(&Odds::new_american(2500)).is_ok());
+    /// assert!(strict.validate(&Odds::new_american(7500)).is_err());
     /// ```
-    pub fn validate(&self) -> Result<(), OddsError> {
-        match &self.format {
+    pub fn validate(&self, odds: &Odds) -> Result<(), OddsError> {
+        match &odds.format {
             OddsFormat::American(value) => {
                 if *value == 0 {
                     Err(OddsError::InvalidAmericanOdds(
                         "American odds cannot be zero".to_string(),
                     ))
-                } else if *value < -100000 || *value > 100000 {
+                } else if value.unsigned_abs() > self.max_american_magnitude {
                     Err(OddsError::ValueOutOfRange(format!(
                         "American odds out of reasonable range: {}",
                         value
@@ -56,7 +139,7 @@ impl Odds {
                         "Decimal odds must be >= 1.0, got: {}",
                         value
                     )))
-                } else if *value > 1000.0 {
+                } else if *value > self.max_decimal {
                     Err(OddsError::ValueOutOfRange(format!(
                         "Decimal odds too large: {}",
                         value
@@ -66,9 +149,10 @@ impl Odds {
                 }
             }
             OddsFormat::Fractional(num, den) => {
+                // A zero numerator is allowed: the odds pay no profit
                 if *den == 0 {
                     Err(OddsError::ZeroDenominator)
-                } else if *num > 10000 || *den > 10000 {
+                } else if *num > self.max_fractional_part || *den > self.max_fractional_part {
                     Err(OddsError::ValueOutOfRange(
                         "Fractional odds values too large".to_string(),
                     ))
@@ -76,6 +160,152 @@ impl Odds {
                     Ok(())
                 }
             }
+            OddsFormat::StartingPrice => Err(OddsError::StartingPriceUndetermined),
         }
     }
 }
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Odds {
+    /// Validates that the odds are mathematically correct and within reasonable ranges.
+    ///
+    /// This method checks that odds values make mathematical sense and are within
+    /// practical limits for real-world betting scenarios. It validates:
+    ///
+    /// - American odds are not zero
+    /// - Decimal odds are >= 1.0 and finite
+    /// - Fractional odds don't have zero denominators (a zero numerator such as
+    ///   `0/1` is allowed and means no profit)
+    /// - All odds are within reasonable ranges
+    ///
+    /// The range limits are those of [`Validator::new`]. Use a custom [`Validator`]
+    /// to apply different limits.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the odds are valid, or an `Err(OddsError)` describing
+    /// the specific validation failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsError};
+    ///
+    /// let valid_odds = Odds::new_decimal(2.5);
+    /// assert!(valid_odds.validate().is_ok());
+    ///
+    /// let invalid_odds = Odds::new_decimal(0.5);
+    /// assert!(invalid_odds.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), OddsError> {
+        Validator::new().validate(self)
+    }
+
+    /// Validates the odds, separating unusual prices from invalid ones.
+    ///
+    /// Only mathematically invalid odds are errors: zero American odds, decimal odds
+    /// below 1.0 or not finite, and zero denominators. Valid odds are reported as
+    /// [`ValidationLevel::Extreme`] when their implied probability is below 1% or
+    /// above 99%, or when they fall outside the limits of [`Odds::validate`], and as
+    /// [`ValidationLevel::Normal`] otherwise. This lets an ingestion pipeline warn
+    /// about extreme prices instead of dropping them.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(ValidationLevel)` for structurally valid odds, or an
+    /// `Err(OddsError)` describing why the odds are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, ValidationLevel};
+    ///
+    /// assert_eq!(Odds::new_decimal(1.5).validate_with_severity().unwrap(), ValidationLevel::Normal);
+    /// assert_eq!(Odds::new_decimal(1500.0).validate_with_severity().unwrap(), ValidationLevel::Extreme);
+    /// assert!(Odds::new_decimal(0.5).validate_with_severity().is_err());
+    /// ```
+    pub fn validate_with_severity(&self) -> Result<ValidationLevel, OddsError> {
+        Validator::new()
+            .max_decimal(f64::INFINITY)
+            .max_american_magnitude(u32::MAX)
+            .max_fractional_part(u32::MAX)
+            .validate(self)?;
+
+        let probability = self.implied_probability()?;
+        let unusual =
+            !(EXTREME_LONGSHOT_PROBABILITY..=EXTREME_FAVORITE_PROBABILITY).contains(&probability);
+        if unusual || self.validate().is_err() {
+            Ok(ValidationLevel::Extreme)
+        } else {
+            Ok(ValidationLevel::Normal)
+        }
+    }
+
+    /// Clamps the odds into the default validation range instead of rejecting them.
+    ///
+    /// The format is preserved. Decimal odds are clamped to `[1.0, 100001.0]` (NaN
+    /// becomes 1.0), American odds to ±10000000, and each fractional part to at most
+    /// 100000. Clamping a fractional part changes the ratio, so 200000/1 becomes
+    /// 100000/1. Structural problems that have no nearest valid value, such as zero
+    /// American odds, a zero denominator or a starting price, are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use odds_converter::{Odds, OddsFormat};
+    ///
+    /// let clamped = Odds::new_decimal(500_000.0).clamp_to_valid();
+    /// assert_eq!(clamped.format(), &OddsFormat::Decimal(100_001.0));
+    ///
+    /// let clamped = Odds::new_american(20_000_000).clamp_to_valid();
+    /// assert_eq!(clamped.format(), &OddsFormat::American(10_000_000));
+    /// ```
+    pub fn clamp_to_valid(&self) -> Odds {
+        let max_american = DEFAULT_MAX_AMERICAN_MAGNITUDE as i32;
+        match self.format {
+            OddsFormat::American(value) => {
+                Odds::new_american_raw(value.clamp(-max_american, max_american))
+            }
+            OddsFormat::Decimal(value) if value.is_nan() => Odds::new_decimal(1.0),
+            OddsFormat::Decimal(value) => Odds::new_decimal(value.clamp(1.0, DEFAULT_MAX_DECIMAL)),
+            OddsFormat::Fractional(num, den) => Odds::new_fractional(
+                num.min(DEFAULT_MAX_FRACTIONAL_PART),
+                den.min(DEFAULT_MAX_FRACTIONAL_PART),
+            ),
+            OddsFormat::StartingPrice => *self,
+        }
+    }
+}
+
+/// Validates that a probability is finite and lies within `[0, 1]`.
+pub(crate) fn validate_probability(probability: f64) -> Result<(), OddsError> {
+    if !probability.is_finite() {
+        Err(OddsError::InfiniteOrNaN)
+    } else if !(0.0..=1.0).contains(&probability) {
+        Err(OddsError::ValueOutOfRange(format!(
+            "Probability must be between 0 and 1, got: {}",
+            probability
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates that a stake is finite and non-negative.
+pub(crate) fn validate_stake(stake: f64) -> Result<(), OddsError> {
+    if !stake.is_finite() {
+        Err(OddsError::InfiniteOrNaN)
+    } else if stake < 0.0 {
+        Err(OddsError::NegativeValue(format!(
+            "Stake cannot be negative, got: {}",
+            stake
+        )))
+    } else {
+        Ok(())
+    }
+}
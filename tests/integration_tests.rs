@@ -1,4 +1,4 @@
-use odds_converter::{Odds, OddsError, OddsFormat};
+use odds_converter::{Market, Odds, OddsError, OddsFormat};
 
 #[test]
 fn test_public_api_completeness() {
@@ -124,7 +124,7 @@ fn test_real_world_scenarios() {
     }
 
     // Scenario 4: Error handling with invalid user input
-    let invalid_inputs = vec!["", "abc", "1/0", "+", "0.5", "1000000"];
+    let invalid_inputs = vec!["", "abc", "1/0", "+", "0.5", "100000000"];
 
     for input in invalid_inputs {
         let parsed: Result<Odds, _> = input.parse();
@@ -496,3 +496,963 @@ fn test_normalized_odds_mathematical_consistency() {
         );
     }
 }
+
+#[test]
+fn test_parlay_vs_straight_threshold() {
+    use odds_converter::parlay::parlay_vs_straight_threshold;
+
+    let threshold = parlay_vs_straight_threshold(&[0.55, 0.55]).unwrap();
+    assert!((threshold - 1.0 / 0.3025).abs() < 1e-12);
+    assert!((threshold - 3.3058).abs() < 0.0001);
+
+    assert!(parlay_vs_straight_threshold(&[]).is_err());
+    assert!(parlay_vs_straight_threshold(&[0.55, 0.0]).is_err());
+    assert!(parlay_vs_straight_threshold(&[0.55, 1.2]).is_err());
+}
+
+#[test]
+fn test_two_leg_qualify_odds() {
+    use odds_converter::knockout::two_leg_qualify_odds;
+
+    // Symmetric legs with a bookmaker margin still devig to an even tie
+    let win = Odds::new_american(160);
+    let draw = Odds::new_american(210);
+    let loss = Odds::new_american(160);
+    let qualify = two_leg_qualify_odds(&win, &draw, &loss, &win, &draw, &loss).unwrap();
+    assert!((qualify.to_decimal().unwrap() - 2.0).abs() < 1e-9);
+
+    // A stronger team in both legs is favoured to qualify
+    let strong_win = Odds::new_decimal(1.5);
+    let strong_draw = Odds::new_decimal(4.0);
+    let strong_loss = Odds::new_decimal(7.0);
+    let favourite = two_leg_qualify_odds(
+        &strong_win,
+        &strong_draw,
+        &strong_loss,
+        &strong_win,
+        &strong_draw,
+        &strong_loss,
+    )
+    .unwrap();
+    assert!(favourite.implied_probability().unwrap() > 0.5);
+
+    let invalid = Odds::new_decimal(0.5);
+    assert!(two_leg_qualify_odds(&invalid, &draw, &loss, &win, &draw, &loss).is_err());
+}
+
+#[test]
+fn test_spread_to_odds() {
+    use odds_converter::spread::spread_to_odds;
+
+    for sigma in [1.0, 13.5, 40.0] {
+        let (favorite, underdog) = spread_to_odds(0.0, sigma).unwrap();
+        assert_eq!(favorite.to_decimal().unwrap(), 2.0);
+        assert_eq!(underdog.to_decimal().unwrap(), 2.0);
+    }
+
+    // A favorite expected to beat the line by one sigma covers ~84.1% of the time
+    let (favorite, underdog) = spread_to_odds(13.5, 13.5).unwrap();
+    assert!((favorite.implied_probability().unwrap() - 0.8413).abs() < 1e-4);
+    let total = favorite.implied_probability().unwrap() + underdog.implied_probability().unwrap();
+    assert!((total - 1.0).abs() < 1e-12);
+
+    assert!(spread_to_odds(3.0, 0.0).is_err());
+    assert!(spread_to_odds(3.0, -1.0).is_err());
+    assert!(spread_to_odds(f64::NAN, 13.5).is_err());
+}
+
+#[test]
+fn test_market_overround_and_devig() {
+    let market = Market::from_odds(vec![Odds::new_american(-110), Odds::new_american(-110)]);
+    let overround = market.overround().unwrap();
+    assert!((overround - (220.0 / 210.0 - 1.0)).abs() < 1e-12);
+    assert!((overround - 0.0476).abs() < 0.0001);
+
+    let fair = market.fair_probabilities().unwrap();
+    assert_eq!(fair.len(), 2);
+    assert!((fair[0] - 0.5).abs() < 1e-12);
+    assert!((fair.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+    for odds in market.fair_odds().unwrap() {
+        assert!((odds.to_decimal().unwrap() - 2.0).abs() < 1e-9);
+    }
+
+    // Three-way market keeps the relative ordering of the outcomes
+    let three_way = Market::from_odds(vec![
+        Odds::new_decimal(2.1),
+        Odds::new_decimal(3.4),
+        Odds::new_decimal(3.6),
+    ]);
+    let fair = three_way.fair_probabilities().unwrap();
+    assert!(fair[0] > fair[1] && fair[1] > fair[2]);
+    assert!((fair.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+    assert!(Market::from_odds(vec![]).overround().is_err());
+    assert!(Market::from_odds(vec![]).fair_probabilities().is_err());
+    assert!(Market::from_odds(vec![Odds::new_american(0)])
+        .fair_odds()
+        .is_err());
+}
+
+#[test]
+fn test_moneyline_to_spread() {
+    use odds_converter::spread::{moneyline_to_spread, spread_to_odds};
+
+    let favorite = Odds::new_american(-150);
+    let underdog = Odds::new_american(130);
+    let spread = moneyline_to_spread(&favorite, &underdog, 13.5).unwrap();
+    assert!(
+        spread > 2.0 && spread < 3.5,
+        "implausible spread {}",
+        spread
+    );
+
+    // Swapping the sides flips the sign
+    let reversed = moneyline_to_spread(&underdog, &favorite, 13.5).unwrap();
+    assert!((spread + reversed).abs() < 1e-6);
+
+    // Round trip through the forward model recovers the devigged probability
+    let (fair_favorite, _) = spread_to_odds(spread, 13.5).unwrap();
+    let expected = 0.6 / (0.6 + 100.0 / 230.0);
+    assert!((fair_favorite.implied_probability().unwrap() - expected).abs() < 1e-6);
+
+    assert!(moneyline_to_spread(&favorite, &underdog, 0.0).is_err());
+    assert!(moneyline_to_spread(&Odds::new_american(0), &underdog, 13.5).is_err());
+}
+
+#[test]
+fn test_brier_score() {
+    use odds_converter::analysis::brier_score;
+
+    // Even-money prices where half of the bets win score exactly 0.25
+    let odds = vec![Odds::new_american(100); 4];
+    let outcomes = [true, false, true, false];
+    assert_eq!(brier_score(&odds, &outcomes).unwrap(), 0.25);
+
+    // A confident correct forecast scores better than an uninformed one
+    let confident = [Odds::new_decimal(1.25)];
+    assert!((brier_score(&confident, &[true]).unwrap() - 0.04).abs() < 1e-12);
+
+    assert!(brier_score(&odds, &[true]).is_err());
+    assert!(brier_score(&[], &[]).is_err());
+    assert!(brier_score(&[Odds::new_decimal(0.5)], &[true]).is_err());
+}
+
+#[test]
+fn test_arbitrage_detection() {
+    use odds_converter::arbitrage;
+
+    let lines = [Odds::new_american(110), Odds::new_american(-105)];
+    let opportunity = arbitrage::detect(&lines).unwrap().unwrap();
+    let expected_total = 100.0 / 210.0 + 105.0 / 205.0;
+    assert!((opportunity.total_implied_probability - expected_total).abs() < 1e-12);
+    assert!((opportunity.profit_margin - (1.0 / expected_total - 1.0)).abs() < 1e-12);
+    assert!((opportunity.stake_fractions.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+    // Every outcome pays out the same amount on a unit bankroll
+    for (fraction, odds) in opportunity.stake_fractions.iter().zip(&lines) {
+        let payout = fraction * odds.to_decimal().unwrap();
+        assert!((payout - (1.0 + opportunity.profit_margin)).abs() < 1e-12);
+    }
+
+    // Standard and single-outcome markets are never arbitrage
+    let standard = [Odds::new_american(-110), Odds::new_american(-110)];
+    assert!(arbitrage::detect(&standard).unwrap().is_none());
+    assert!(arbitrage::detect(&[Odds::new_decimal(50.0)])
+        .unwrap()
+        .is_none());
+    assert!(arbitrage::detect(&[]).unwrap().is_none());
+
+    let with_invalid = [Odds::new_american(110), Odds::new_decimal(f64::NAN)];
+    assert_eq!(
+        arbitrage::detect(&with_invalid),
+        Err(OddsError::InfiniteOrNaN)
+    );
+}
+
+#[test]
+fn test_log_loss() {
+    use odds_converter::analysis::log_loss;
+
+    // 80% favourites that win four times out of five
+    let outcomes = [true, true, true, true, false];
+    let calibrated = vec![Odds::new_decimal(1.25); 5];
+    let poorly_calibrated = vec![Odds::new_decimal(5.0); 5];
+
+    let good = log_loss(&calibrated, &outcomes).unwrap();
+    let bad = log_loss(&poorly_calibrated, &outcomes).unwrap();
+    assert!(good < bad);
+
+    // Certain prices are clamped instead of producing infinity
+    let certain = log_loss(&[Odds::new_decimal(1.0)], &[false]).unwrap();
+    assert!(certain.is_finite() && certain > 30.0);
+
+    assert!(log_loss(&calibrated, &outcomes[..2]).is_err());
+}
+
+#[test]
+fn test_calibration_bins() {
+    use odds_converter::analysis::calibration_bins;
+
+    let odds = [
+        Odds::new_decimal(5.0),   // 20%
+        Odds::new_decimal(10.0),  // 10%
+        Odds::new_decimal(2.0),   // 50%
+        Odds::new_decimal(1.25),  // 80%
+        Odds::new_decimal(1.0),   // 100%
+        Odds::new_american(-300), // 75%
+    ];
+    let outcomes = [false, true, false, true, true, false];
+
+    let bins = calibration_bins(&odds, &outcomes, 4).unwrap();
+    assert_eq!(bins.len(), 4);
+
+    let (mean, rate, count) = bins[0];
+    assert_eq!(count, 2);
+    assert!((mean - 0.15).abs() < 1e-12);
+    assert_eq!(rate, 0.5);
+
+    assert_eq!(bins[1], (0.0, 0.0, 0));
+
+    let (mean, rate, count) = bins[2];
+    assert_eq!(count, 1);
+    assert_eq!(mean, 0.5);
+    assert_eq!(rate, 0.0);
+
+    let (mean, rate, count) = bins[3];
+    assert_eq!(count, 3);
+    assert!((mean - (0.8 + 1.0 + 0.75) / 3.0).abs() < 1e-12);
+    assert!((rate - 2.0 / 3.0).abs() < 1e-12);
+
+    assert!(calibration_bins(&odds, &outcomes, 0).is_err());
+    assert!(calibration_bins(&odds, &outcomes[..1], 4).is_err());
+}
+
+#[test]
+fn test_clv_record() {
+    use odds_converter::analysis::clv_record;
+
+    let bets = [
+        (Odds::new_decimal(2.2), Odds::new_decimal(2.0)), // +10%
+        (Odds::new_american(-110), Odds::new_american(-110)), // even
+        (Odds::new_decimal(1.8), Odds::new_decimal(2.0)), // -10%
+        (Odds::new_fractional(3, 1), Odds::new_decimal(2.5)), // +60%
+    ];
+    let (average, beat, missed) = clv_record(&bets).unwrap();
+    assert_eq!(beat, 2);
+    assert_eq!(missed, 2);
+    assert!((average - 0.15).abs() < 1e-12);
+
+    assert!(clv_record(&[]).is_err());
+    assert!(clv_record(&[(Odds::new_decimal(2.0), Odds::new_american(0))]).is_err());
+}
+
+#[test]
+fn test_best_price_for_side() {
+    use odds_converter::analysis::best_price_for_side;
+
+    let prices = [
+        Odds::new_american(-110),
+        Odds::new_american(-105),
+        Odds::new_american(-108),
+    ];
+    let (index, best) = best_price_for_side(&prices).unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(best.format(), &OddsFormat::American(-105));
+
+    // Mixed formats compare on payout
+    let mixed = [
+        Odds::new_decimal(2.4),
+        Odds::new_fractional(3, 2),
+        Odds::new_american(145),
+    ];
+    assert_eq!(best_price_for_side(&mixed).unwrap().0, 1);
+
+    assert!(best_price_for_side(&[]).is_err());
+    assert!(best_price_for_side(&[Odds::new_american(0)]).is_err());
+}
+
+#[test]
+fn test_middle_stakes() {
+    use odds_converter::middle::middle_stakes;
+
+    // Over 210.5 at -110 and Under 213.5 at -110 with $1000 total
+    let over = Odds::new_american(-110);
+    let under = Odds::new_american(-110);
+    let (over_stake, under_stake) = middle_stakes(&over, &under, 1000.0).unwrap();
+    assert!((over_stake + under_stake - 1000.0).abs() < 1e-9);
+    assert!((over_stake - 500.0).abs() < 1e-9);
+
+    // Missing the middle roughly breaks even: only the vig is lost
+    let miss = over.payout(over_stake).unwrap() - 1000.0;
+    assert!(miss < 0.0 && miss > -50.0);
+    let other_miss = under.payout(under_stake).unwrap() - 1000.0;
+    assert!((miss - other_miss).abs() < 1e-9);
+
+    // Hitting the middle wins both bets
+    let hit = over.payout(over_stake).unwrap() + under.payout(under_stake).unwrap() - 1000.0;
+    assert!(hit > 900.0);
+
+    // Uneven prices shift more stake onto the shorter side
+    let (short, long) =
+        middle_stakes(&Odds::new_american(-150), &Odds::new_american(130), 100.0).unwrap();
+    assert!(short > long);
+
+    assert!(middle_stakes(&over, &under, -1.0).is_err());
+    assert!(middle_stakes(&Odds::new_american(0), &under, 100.0).is_err());
+}
+
+#[test]
+fn test_middle_expected_value() {
+    use odds_converter::middle::middle_expected_value;
+
+    let over = Odds::new_american(-110);
+    let under = Odds::new_american(-110);
+
+    let likely = middle_expected_value(&over, &under, 0.15, 1000.0).unwrap();
+    assert!(likely > 0.0);
+
+    let unlikely = middle_expected_value(&over, &under, 0.01, 1000.0).unwrap();
+    assert!(unlikely < 0.0);
+
+    // Break-even middle probability equals the vig lost on a miss
+    let miss_loss = 1000.0 - 500.0 * over.to_decimal().unwrap();
+    let hit_win = 1000.0 * over.to_decimal().unwrap() - 1000.0;
+    let break_even = miss_loss / (hit_win + miss_loss);
+    let ev = middle_expected_value(&over, &under, break_even, 1000.0).unwrap();
+    assert!(ev.abs() < 1e-9);
+
+    assert!(middle_expected_value(&over, &under, 1.5, 1000.0).is_err());
+    assert!(middle_expected_value(&over, &under, 0.1, -1.0).is_err());
+}
+
+#[test]
+fn test_harville_finish_probabilities() {
+    use odds_converter::racing::harville_finish_probabilities;
+
+    // Fair prices for win probabilities of 50%, 30% and 20%
+    let field = [
+        Odds::new_decimal(2.0),
+        Odds::new_decimal(1.0 / 0.3),
+        Odds::new_decimal(5.0),
+    ];
+    let probabilities = harville_finish_probabilities(&field).unwrap();
+    assert_eq!(probabilities.len(), 3);
+
+    let favourite = &probabilities[0];
+    assert!((favourite[0] - 0.5).abs() < 1e-12);
+    // Second: 0.3 * 0.5/0.7 + 0.2 * 0.5/0.8
+    assert!((favourite[1] - (0.3 * 0.5 / 0.7 + 0.2 * 0.5 / 0.8)).abs() < 1e-12);
+    assert!((favourite.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+    for position in 0..3 {
+        let total: f64 = probabilities.iter().map(|runner| runner[position]).sum();
+        assert!(
+            (total - 1.0).abs() < 1e-12,
+            "position {} sums to {}",
+            position,
+            total
+        );
+    }
+
+    // Overround is removed before applying the model
+    let with_vig = [
+        Odds::new_decimal(1.8),
+        Odds::new_decimal(3.0),
+        Odds::new_decimal(4.5),
+    ];
+    let first: f64 = harville_finish_probabilities(&with_vig)
+        .unwrap()
+        .iter()
+        .map(|runner| runner[0])
+        .sum();
+    assert!((first - 1.0).abs() < 1e-12);
+
+    assert!(harville_finish_probabilities(&[]).is_err());
+    assert!(harville_finish_probabilities(&[Odds::new_american(0)]).is_err());
+}
+
+#[test]
+fn test_exacta_odds() {
+    use odds_converter::racing::exacta_odds;
+
+    let field = [
+        Odds::new_decimal(2.5),
+        Odds::new_decimal(4.0),
+        Odds::new_decimal(5.0),
+        Odds::new_decimal(8.0),
+    ];
+    let total = 1.0 / 2.5 + 1.0 / 4.0 + 1.0 / 5.0 + 1.0 / 8.0;
+    let p: Vec<f64> = [2.5, 4.0, 5.0, 8.0]
+        .iter()
+        .map(|d| 1.0 / d / total)
+        .collect();
+
+    let exacta = exacta_odds(&field, 1, 3).unwrap();
+    let expected = p[1] * p[3] / (1.0 - p[1]);
+    assert!((exacta.implied_probability().unwrap() - expected).abs() < 1e-12);
+
+    // All exactas together cover every possible 1-2 finish
+    let mut sum = 0.0;
+    for first in 0..4 {
+        for second in 0..4 {
+            if first != second {
+                sum += exacta_odds(&field, first, second)
+                    .unwrap()
+                    .implied_probability()
+                    .unwrap();
+            }
+        }
+    }
+    assert!((sum - 1.0).abs() < 1e-12);
+
+    assert!(exacta_odds(&field, 2, 2).is_err());
+    assert!(exacta_odds(&field, 0, 4).is_err());
+}
+
+#[test]
+fn test_place_probability() {
+    use odds_converter::racing::{harville_finish_probabilities, place_probability};
+
+    let field = [
+        Odds::new_decimal(2.5),
+        Odds::new_decimal(4.0),
+        Odds::new_decimal(5.0),
+        Odds::new_decimal(8.0),
+    ];
+    let finishes = harville_finish_probabilities(&field).unwrap();
+
+    let win = place_probability(&field, 0, 1).unwrap();
+    let top_two = place_probability(&field, 0, 2).unwrap();
+    assert!((win - finishes[0][0]).abs() < 1e-12);
+    assert!((top_two - (finishes[0][0] + finishes[0][1])).abs() < 1e-12);
+    assert!(top_two > win);
+
+    // Every runner finishes somewhere in the field
+    assert!((place_probability(&field, 3, 4).unwrap() - 1.0).abs() < 1e-12);
+
+    // Expected number of runners placing equals the places paid
+    let placed: f64 = (0..4)
+        .map(|r| place_probability(&field, r, 2).unwrap())
+        .sum();
+    assert!((placed - 2.0).abs() < 1e-12);
+
+    assert!(place_probability(&field, 4, 2).is_err());
+    assert!(place_probability(&field, 0, 0).is_err());
+    assert!(place_probability(&field, 0, 5).is_err());
+}
+
+#[test]
+fn test_longshot_bias() {
+    use odds_converter::analysis::longshot_bias;
+
+    // A short favorite priced close to fair, with the margin loaded onto the
+    // outsiders
+    let biased = [
+        Odds::new_decimal(1.1),
+        Odds::new_decimal(4.0),
+        Odds::new_decimal(5.0),
+    ];
+    assert!(longshot_bias(&biased).unwrap() > 0.5);
+
+    // A balanced book has no longshots to load
+    let balanced = [
+        Odds::new_decimal(2.8),
+        Odds::new_decimal(2.8),
+        Odds::new_decimal(2.8),
+    ];
+    assert_eq!(longshot_bias(&balanced).unwrap(), 0.0);
+
+    // A fair book has no overround to attribute
+    let fair = [
+        Odds::new_decimal(2.0),
+        Odds::new_decimal(4.0),
+        Odds::new_decimal(4.0),
+    ];
+    assert_eq!(longshot_bias(&fair).unwrap(), 0.0);
+
+    let correlation = longshot_bias(&[
+        Odds::new_american(-300),
+        Odds::new_american(250),
+        Odds::new_american(900),
+    ])
+    .unwrap();
+    assert!((-1.0..=1.0).contains(&correlation));
+
+    // Two-way markets always correlate at ±1, so they are rejected
+    for two_way in [[1.9, 1.95], [1.5, 2.6], [1.05, 10.0]] {
+        let market = two_way.map(Odds::new_decimal);
+        assert!(matches!(
+            longshot_bias(&market),
+            Err(OddsError::ValueOutOfRange(_))
+        ));
+    }
+
+    // Nearly collinear points stay within [-1, 1] despite rounding
+    for longest in [4.0, 10.0, 50.0, 200.0] {
+        let market = [1.05, 3.0, longest].map(Odds::new_decimal);
+        let correlation = longshot_bias(&market).unwrap();
+        assert!((-1.0..=1.0).contains(&correlation));
+    }
+
+    assert!(longshot_bias(&[Odds::new_decimal(1.5)]).is_err());
+    assert!(longshot_bias(&[
+        Odds::new_decimal(1.5),
+        Odds::new_decimal(3.0),
+        Odds::new_decimal(0.5)
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_simultaneous_kelly() {
+    use odds_converter::kelly::simultaneous_kelly;
+
+    // Only the first side is priced above its true probability
+    let odds = [Odds::new_decimal(2.1), Odds::new_decimal(1.8)];
+    let stakes = simultaneous_kelly(&odds, &[0.55, 0.45]).unwrap();
+    assert!(stakes[0] > 0.0);
+    assert_eq!(stakes[1], 0.0);
+
+    // With a single bet the allocation matches the single-outcome Kelly fraction
+    let single = odds[0].kelly_fraction(0.55).unwrap();
+    assert!((stakes[0] - single).abs() < 1e-12);
+
+    // No edge anywhere means no bets
+    assert_eq!(
+        simultaneous_kelly(&odds, &[0.45, 0.55]).unwrap(),
+        vec![0.0, 0.0]
+    );
+    let fair = [Odds::new_decimal(2.0), Odds::new_decimal(2.0)];
+    assert_eq!(
+        simultaneous_kelly(&fair, &[0.5, 0.5]).unwrap(),
+        vec![0.0, 0.0]
+    );
+
+    // Two overlays in a three-way market are both backed
+    let three_way = [
+        Odds::new_decimal(3.0),
+        Odds::new_decimal(4.5),
+        Odds::new_decimal(1.8),
+    ];
+    let stakes = simultaneous_kelly(&three_way, &[0.4, 0.25, 0.35]).unwrap();
+    assert!(stakes[0] > 0.0 && stakes[1] > 0.0);
+    assert_eq!(stakes[2], 0.0);
+    assert!(stakes.iter().sum::<f64>() < 1.0);
+
+    assert!(simultaneous_kelly(&odds, &[0.5]).is_err());
+    assert!(simultaneous_kelly(&[], &[]).is_err());
+    assert!(simultaneous_kelly(&odds, &[0.6, 0.5]).is_err());
+    assert!(simultaneous_kelly(&odds, &[1.2, -0.2]).is_err());
+}
+
+#[test]
+fn test_margin_band_odds() {
+    use odds_converter::spread::margin_band_odds;
+
+    let narrow = margin_band_odds(3.0, 1.0, 6.0, 13.5).unwrap();
+    let wide = margin_band_odds(3.0, 1.0, 13.0, 13.5).unwrap();
+    assert!(wide.to_decimal().unwrap() < narrow.to_decimal().unwrap());
+
+    // The band covers either team, so the favorite's sign does not matter
+    let favorite = margin_band_odds(7.0, 1.0, 6.0, 13.5).unwrap();
+    let underdog = margin_band_odds(-7.0, 1.0, 6.0, 13.5).unwrap();
+    assert!((favorite.to_decimal().unwrap() - underdog.to_decimal().unwrap()).abs() < 1e-9);
+
+    // A band covering every margin is a certainty
+    let everything = margin_band_odds(3.0, 0.0, 1000.0, 13.5).unwrap();
+    assert!((everything.to_decimal().unwrap() - 1.0).abs() < 1e-9);
+
+    assert!(margin_band_odds(3.0, 6.0, 1.0, 13.5).is_err());
+    assert!(margin_band_odds(3.0, -1.0, 6.0, 13.5).is_err());
+    assert!(margin_band_odds(3.0, 1.0, 6.0, 0.0).is_err());
+    assert!(margin_band_odds(3.0, 1.0, f64::NAN, 13.5).is_err());
+    assert!(margin_band_odds(3.0, 5.0, 5.0, 13.5).is_err());
+}
+
+#[test]
+fn test_shin_z() {
+    use odds_converter::analysis::shin_z;
+
+    let prices = [3.0, 3.4, 2.4];
+    let market: Vec<Odds> = prices.iter().map(|&d| Odds::new_decimal(d)).collect();
+    let z = shin_z(&market).unwrap();
+    assert!(z > 0.0 && z < 0.1);
+
+    // The fitted z satisfies Shin's fixed-point equation for n outcomes:
+    // z = (sum(sqrt(z^2 + 4 (1 - z) q_i^2 / B)) - 2) / (n - 2)
+    let implied: Vec<f64> = prices.iter().map(|d| 1.0 / d).collect();
+    let booksum: f64 = implied.iter().sum();
+    let roots: f64 = implied
+        .iter()
+        .map(|q| (z * z + 4.0 * (1.0 - z) * q * q / booksum).sqrt())
+        .sum();
+    assert!((z - (roots - 2.0) / (prices.len() as f64 - 2.0)).abs() < 1e-9);
+
+    // For two outcomes the fair probabilities must sum to 1
+    let two_way = [Odds::new_american(-150), Odds::new_american(130)];
+    let z = shin_z(&two_way).unwrap();
+    let implied: Vec<f64> = two_way
+        .iter()
+        .map(|o| o.implied_probability().unwrap())
+        .collect();
+    let booksum: f64 = implied.iter().sum();
+    let fair: f64 = implied
+        .iter()
+        .map(|q| ((z * z + 4.0 * (1.0 - z) * q * q / booksum).sqrt() - z) / (2.0 * (1.0 - z)))
+        .sum();
+    assert!((fair - 1.0).abs() < 1e-9);
+
+    assert!(shin_z(&[Odds::new_decimal(1.5)]).is_err());
+    assert!(shin_z(&[Odds::new_decimal(2.1), Odds::new_decimal(2.1)]).is_err());
+    assert!(shin_z(&[Odds::new_decimal(2.0), Odds::new_decimal(0.5)]).is_err());
+}
+
+#[test]
+fn test_efficiency_vs_reference() {
+    use odds_converter::analysis::efficiency_vs_reference;
+
+    let sharp = [
+        Odds::new_decimal(2.1),
+        Odds::new_decimal(3.5),
+        Odds::new_decimal(3.6),
+    ];
+    assert_eq!(efficiency_vs_reference(&sharp, &sharp).unwrap(), 0.0);
+
+    // The same fair line with a bigger margin is still perfectly efficient
+    let vigged = [Odds::new_decimal(1.9), Odds::new_decimal(1.9)];
+    let fair = [Odds::new_decimal(2.0), Odds::new_decimal(2.0)];
+    assert!(efficiency_vs_reference(&vigged, &fair).unwrap() < 1e-12);
+
+    // Fair probabilities of 0.6/0.4 against 0.5/0.5 differ by 0.1 on each side
+    let soft = [Odds::new_decimal(1.0 / 0.6), Odds::new_decimal(2.5)];
+    let score = efficiency_vs_reference(&soft, &fair).unwrap();
+    assert!((score - 0.1).abs() < 1e-12);
+
+    assert!(efficiency_vs_reference(&soft, &sharp).is_err());
+    assert!(efficiency_vs_reference(&[], &[]).is_err());
+    assert!(efficiency_vs_reference(&[Odds::new_decimal(0.5)], &[Odds::new_decimal(2.0)]).is_err());
+}
+
+#[test]
+fn test_market_two_way_fair() {
+    let standard = Market::from_odds(vec![Odds::new_american(-110), Odds::new_american(-110)]);
+    let (first, second) = standard.two_way_fair().unwrap();
+    assert!((first.to_decimal().unwrap() - 2.0).abs() < 1e-9);
+    assert!((second.to_decimal().unwrap() - 2.0).abs() < 1e-9);
+
+    let moneyline = Market::from_odds(vec![Odds::new_american(-150), Odds::new_american(130)]);
+    let (favorite, underdog) = moneyline.two_way_fair().unwrap();
+    let total = favorite.implied_probability().unwrap() + underdog.implied_probability().unwrap();
+    assert!((total - 1.0).abs() < 1e-12);
+    assert!(favorite.to_american().unwrap() < 0);
+    assert!(underdog.to_american().unwrap() > 0);
+
+    let three_way = Market::from_odds(vec![
+        Odds::new_decimal(2.5),
+        Odds::new_decimal(3.2),
+        Odds::new_decimal(3.0),
+    ]);
+    assert!(three_way.two_way_fair().is_err());
+    assert!(Market::from_odds(vec![Odds::new_decimal(1.9)])
+        .two_way_fair()
+        .is_err());
+    assert!(
+        Market::from_odds(vec![Odds::new_decimal(1.9), Odds::new_decimal(0.5)])
+            .two_way_fair()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_result_sigma() {
+    use odds_converter::analysis::result_sigma;
+
+    // An even-money favorite losing is one standard deviation from expectation
+    let even_money = Odds::new_decimal(2.0);
+    assert!((result_sigma(&even_money, false).unwrap() - 1.0).abs() < 1e-12);
+    assert!((result_sigma(&even_money, true).unwrap() - 1.0).abs() < 1e-12);
+
+    // Upsets are further from expectation than expected wins
+    let favorite = Odds::new_american(-300);
+    let upset = result_sigma(&favorite, false).unwrap();
+    let expected = result_sigma(&favorite, true).unwrap();
+    assert!((upset - 3.0_f64.sqrt()).abs() < 1e-12);
+    assert!((expected - 1.0 / 3.0_f64.sqrt()).abs() < 1e-12);
+
+    let certain = Odds::new_decimal(1.0);
+    assert_eq!(result_sigma(&certain, true).unwrap(), 0.0);
+    assert!(result_sigma(&certain, false).is_err());
+    assert!(result_sigma(&Odds::new_american(0), true).is_err());
+}
+
+#[test]
+fn test_simulate_market_odds() {
+    use odds_converter::simulation::simulate_market_odds;
+
+    let true_probs = [0.45, 0.3, 0.25];
+    let first = simulate_market_odds(&true_probs, 0.05, 0.02, 42).unwrap();
+    let second = simulate_market_odds(&true_probs, 0.05, 0.02, 42).unwrap();
+    assert_eq!(first, second);
+    for (a, b) in first.iter().zip(&second) {
+        assert_eq!(a.format(), b.format());
+        assert!(matches!(a.format(), OddsFormat::American(_)));
+    }
+
+    let other_seed = simulate_market_odds(&true_probs, 0.05, 0.02, 43).unwrap();
+    assert_ne!(
+        first
+            .iter()
+            .map(|o| o.to_american().unwrap())
+            .collect::<Vec<_>>(),
+        other_seed
+            .iter()
+            .map(|o| o.to_american().unwrap())
+            .collect::<Vec<_>>()
+    );
+
+    // Rounding to whole American odds only moves the overround slightly
+    for seed in 0..20 {
+        let odds = simulate_market_odds(&true_probs, 0.05, 0.02, seed).unwrap();
+        let overround = Market::from_odds(odds).overround().unwrap();
+        assert!((overround - 0.05).abs() < 0.005);
+    }
+
+    // Without noise the fair prices are reproduced
+    let fair = simulate_market_odds(&[0.5, 0.5], 0.0, 0.0, 1).unwrap();
+    assert_eq!(fair[0].to_american().unwrap(), 100);
+
+    assert!(simulate_market_odds(&[0.5, 0.4], 0.05, 0.02, 1).is_err());
+    assert!(simulate_market_odds(&[], 0.05, 0.02, 1).is_err());
+    assert!(simulate_market_odds(&[1.5, -0.5], 0.05, 0.02, 1).is_err());
+    assert!(simulate_market_odds(&true_probs, -0.01, 0.02, 1).is_err());
+    assert!(simulate_market_odds(&true_probs, 0.05, -0.02, 1).is_err());
+    assert!(simulate_market_odds(&true_probs, 0.05, f64::NAN, 1).is_err());
+}
+
+#[test]
+fn test_batch_parse_all() {
+    use odds_converter::batch::parse_all;
+
+    let (odds, failures) = parse_all(["+150", "abc", "2.5"]);
+    assert_eq!(odds.len(), 2);
+    assert_eq!(odds[0].format(), &OddsFormat::American(150));
+    assert_eq!(odds[1].format(), &OddsFormat::Decimal(2.5));
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, "abc");
+    assert!(matches!(failures[0].1, OddsError::ParseError(_)));
+
+    // Validation failures are reported with their input too
+    let inputs = [
+        String::from("0.5"),
+        String::from("3/2"),
+        String::from("1/0"),
+    ];
+    let (odds, failures) = parse_all(inputs.iter().map(String::as_str));
+    assert_eq!(odds.len(), 1);
+    let labels: Vec<&str> = failures.iter().map(|(input, _)| input.as_str()).collect();
+    assert_eq!(labels, ["0.5", "1/0"]);
+
+    let (odds, failures) = parse_all(std::iter::empty());
+    assert!(odds.is_empty() && failures.is_empty());
+}
+
+#[test]
+fn test_dutching_stakes() {
+    use odds_converter::dutching;
+
+    let even_money = [Odds::new_decimal(2.0), Odds::new_decimal(2.0)];
+    assert_eq!(
+        dutching::stakes(&even_money, 100.0).unwrap(),
+        vec![50.0, 50.0]
+    );
+
+    // Every winner returns the same amount
+    let selections = [
+        Odds::new_fractional(5, 2),
+        Odds::new_american(400),
+        Odds::new_decimal(8.0),
+    ];
+    let stakes = dutching::stakes(&selections, 100.0).unwrap();
+    assert!((stakes.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+    let returns: Vec<f64> = selections
+        .iter()
+        .zip(&stakes)
+        .map(|(odds, &stake)| odds.payout(stake).unwrap())
+        .collect();
+    assert!(returns.iter().all(|r| (r - returns[0]).abs() < 1e-9));
+
+    assert_eq!(dutching::stakes(&even_money, 0.0).unwrap(), vec![0.0, 0.0]);
+    assert!(dutching::stakes(&[], 100.0).is_err());
+    assert!(matches!(
+        dutching::stakes(&even_money, -100.0),
+        Err(OddsError::NegativeValue(_))
+    ));
+    assert!(dutching::stakes(&[Odds::new_decimal(0.5)], 100.0).is_err());
+}
+
+#[test]
+fn test_market_builder_named_outcomes() {
+    let market = Market::new("Arsenal vs Chelsea")
+        .add("Arsenal", Odds::new_decimal(1.80))
+        .unwrap()
+        .add("Draw", Odds::new_fractional(7, 2))
+        .unwrap()
+        .add("Chelsea", Odds::new_american(450))
+        .unwrap();
+
+    assert_eq!(market.description(), "Arsenal vs Chelsea");
+    assert_eq!(market.outcomes().len(), 3);
+    assert_eq!(market.odds().len(), 3);
+
+    let favorite = market.favorite().unwrap();
+    assert_eq!(favorite.name(), "Arsenal");
+    assert!(favorite.is_favorite());
+    assert!(!market.outcomes()[1].is_favorite());
+    assert!(!market.outcomes()[2].is_favorite());
+
+    let expected = 1.0 / 1.80 + 2.0 / 9.0 + 1.0 / 5.5;
+    assert!((market.total_probability().unwrap() - expected).abs() < 1e-9);
+    assert!((market.overround().unwrap() - (expected - 1.0)).abs() < 1e-9);
+
+    // Invalid odds are rejected when added
+    assert!(Market::new("Bad")
+        .add("Nobody", Odds::new_decimal(0.5))
+        .is_err());
+    assert!(Market::new("Empty").favorite().is_none());
+}
+
+#[test]
+fn test_market_fair_american() {
+    let standard = Market::from_odds(vec![Odds::new_american(-110), Odds::new_american(-110)]);
+    assert_eq!(standard.fair_american().unwrap(), vec![100, 100]);
+
+    // Every fair line is outside the illegal -99..+99 gap
+    let three_way = Market::from_odds(vec![
+        Odds::new_decimal(1.95),
+        Odds::new_decimal(3.6),
+        Odds::new_decimal(4.2),
+    ]);
+    for american in three_way.fair_american().unwrap() {
+        assert!(american.abs() >= 100);
+    }
+
+    assert!(Market::from_odds(vec![]).fair_american().is_err());
+    assert!(Market::from_odds(vec![Odds::new_decimal(1.5)])
+        .fair_american()
+        .is_err());
+}
+
+#[test]
+fn test_probability_market_summary() {
+    use odds_converter::probability::market_summary;
+
+    // The five-horse field from the sports_betting example
+    let field = [
+        Odds::new_fractional(2, 1),
+        Odds::new_fractional(5, 2),
+        Odds::new_american(400),
+        Odds::new_decimal(6.0),
+        Odds::new_american(-150),
+    ];
+    let summary = market_summary(&field).unwrap();
+
+    assert_eq!(summary.per_outcome.len(), 5);
+    assert!((summary.per_outcome[0] - 1.0 / 3.0).abs() < 1e-9);
+    assert!((summary.per_outcome[4] - 0.6).abs() < 1e-9);
+    assert!((summary.total - 1.585_714).abs() < 1e-6);
+    // The example reports a 58.6% overround
+    assert_eq!(format!("{:.1}%", summary.overround * 100.0), "58.6%");
+    assert!(
+        (summary.overround - Market::from_odds(field.to_vec()).overround().unwrap()).abs() < 1e-12
+    );
+
+    assert!(matches!(
+        market_summary(&[]),
+        Err(OddsError::ValueOutOfRange(_))
+    ));
+    assert!(market_summary(&[Odds::new_decimal(2.0), Odds::new_decimal(0.5)]).is_err());
+
+    // Empty and invalid markets are reported exactly as Market reports them
+    for odds in [vec![], vec![Odds::new_decimal(2.0), Odds::new_decimal(0.5)]] {
+        assert_eq!(
+            market_summary(&odds).unwrap_err(),
+            Market::from_odds(odds).total_probability().unwrap_err()
+        );
+    }
+}
+
+#[test]
+fn test_market_hold_percentage() {
+    let standard = Market::from_odds(vec![Odds::new_american(-110), Odds::new_american(-110)]);
+    let hold = standard.hold_percentage().unwrap();
+    assert!((hold - 100.0 / 22.0).abs() < 1e-9);
+    assert!((hold - 4.55).abs() < 0.01);
+    // Hold is smaller than the overround for the same market
+    assert!(hold < standard.overround().unwrap() * 100.0);
+
+    let fair = Market::from_odds(vec![Odds::new_decimal(2.0), Odds::new_decimal(2.0)]);
+    assert_eq!(fair.hold_percentage().unwrap(), 0.0);
+
+    assert!(Market::from_odds(vec![]).hold_percentage().is_err());
+}
+
+#[test]
+fn test_analysis_best_worst() {
+    use odds_converter::analysis::best_worst;
+
+    let prices = [
+        Odds::new_american(-110),   // 1.909
+        Odds::new_fractional(5, 2), // 3.5
+        Odds::new_decimal(1.5),
+        Odds::new_american(250), // 3.5, ties with 5/2
+        Odds::new_decimal(1.25),
+        Odds::new_fractional(1, 4), // 1.25, ties with 1.25
+    ];
+    assert_eq!(best_worst(&prices).unwrap(), Some((1, 4)));
+
+    assert_eq!(best_worst(&[Odds::new_decimal(2.0)]).unwrap(), Some((0, 0)));
+    assert_eq!(best_worst(&[]).unwrap(), None);
+    assert!(best_worst(&[Odds::new_decimal(2.0), Odds::new_decimal(f64::NAN)]).is_err());
+    assert!(best_worst(&[Odds::new_fractional(1, 0)]).is_err());
+}
+
+#[test]
+fn test_odds_iterator_ext() {
+    use odds_converter::OddsIteratorExt;
+
+    // The field from the sports betting example
+    let horses = [
+        Odds::new_fractional(2, 1),
+        Odds::new_fractional(5, 2),
+        Odds::new_american(400),
+        Odds::new_decimal(6.0),
+        Odds::new_american(-150),
+    ];
+    let expected_total = 1.0 / 3.0 + 1.0 / 3.5 + 0.2 + 1.0 / 6.0 + 0.6;
+
+    let total = horses.iter().total_implied_probability().unwrap();
+    assert!((total - expected_total).abs() < 1e-12);
+    assert_eq!(horses.iter().best_decimal().unwrap(), 6.0);
+    let average = horses.iter().average_decimal().unwrap();
+    assert!((average - (3.0 + 3.5 + 5.0 + 6.0 + 5.0 / 3.0) / 5.0).abs() < 1e-12);
+
+    // Works on owned items and in the middle of an adapter chain
+    let owned: Vec<Odds> = horses.to_vec();
+    assert_eq!(owned.into_iter().best_decimal().unwrap(), 6.0);
+    let outsiders = horses
+        .iter()
+        .filter(|odds| odds.to_decimal().unwrap() >= 5.0)
+        .total_implied_probability()
+        .unwrap();
+    assert!((outsiders - (0.2 + 1.0 / 6.0)).abs() < 1e-12);
+
+    let empty: [Odds; 0] = [];
+    assert!(matches!(
+        empty.iter().total_implied_probability(),
+        Err(OddsError::ValueOutOfRange(_))
+    ));
+    assert!(empty.iter().best_decimal().is_err());
+    assert!(empty.iter().average_decimal().is_err());
+    assert!([Odds::new_american(0)].iter().best_decimal().is_err());
+}